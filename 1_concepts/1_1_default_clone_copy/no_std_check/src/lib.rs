@@ -0,0 +1,24 @@
+//! Build test of the `no_std` path of [`step_1_1`], failing to compile once
+//! its core [`Point`] geometry starts requiring `std`.
+//!
+//! Build it alone (so no other workspace member enables the `std` feature),
+//! preferably for a target without `std` at all:
+//! ```bash
+//! cargo build -p step_1_1_no_std_check --target thumbv7em-none-eabi
+//! ```
+
+#![no_std]
+
+use step_1_1::Point;
+
+/// Exercises the integer [`Point`] methods available without `std`.
+#[must_use]
+pub fn step_towards(from: Point, to: Point) -> Point {
+    from + (to - from).signum()
+}
+
+/// Exercises the [`Point::distance_to()`] fallback available without `std`.
+#[must_use]
+pub fn distance(a: Point, b: Point) -> f64 {
+    a.distance_to(b)
+}
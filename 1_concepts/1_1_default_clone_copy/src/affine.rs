@@ -0,0 +1,188 @@
+use crate::Point;
+
+/// 2D affine transformation, stored as the top two rows of a 3x3 matrix
+/// acting on column vectors `(x, y, 1)`:
+///
+/// ```text
+/// | a  b  tx |
+/// | c  d  ty |
+/// ```
+///
+/// The matrix is [`f64`] based, so rotations by arbitrary angles compose
+/// without accumulating integer error, and a transformed [`Point`] is only
+/// rounded back to integer coordinates at the very end, with halves rounded
+/// away from zero.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Affine2 {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+    pub d: f64,
+    pub tx: f64,
+    pub ty: f64,
+}
+
+impl Affine2 {
+    /// [`Affine2`] leaving every [`Point`] in place.
+    pub const IDENTITY: Self = Self {
+        a: 1.0,
+        b: 0.0,
+        c: 0.0,
+        d: 1.0,
+        tx: 0.0,
+        ty: 0.0,
+    };
+
+    /// Creates a new [`Affine2`] moving every [`Point`] by the provided
+    /// `offset`.
+    #[must_use]
+    pub fn translation(offset: Point) -> Self {
+        Self {
+            tx: f64::from(offset.x),
+            ty: f64::from(offset.y),
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Creates a new [`Affine2`] scaling coordinates relative to the origin by
+    /// the provided `sx` and `sy` factors.
+    #[must_use]
+    pub fn scaling(sx: f64, sy: f64) -> Self {
+        Self {
+            a: sx,
+            d: sy,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Creates a new [`Affine2`] rotating around the origin by the provided
+    /// `angle` in radians, counter-clockwise assuming the `y` axis points up.
+    #[must_use]
+    pub fn rotation(angle: f64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self {
+            a: cos,
+            b: -sin,
+            c: sin,
+            d: cos,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Composes this [`Affine2`] with the `next` one, so the returned
+    /// transformation applies this one first and the `next` one afterwards.
+    #[must_use]
+    pub fn then(self, next: Self) -> Self {
+        Self {
+            a: next.a * self.a + next.b * self.c,
+            b: next.a * self.b + next.b * self.d,
+            c: next.c * self.a + next.d * self.c,
+            d: next.c * self.b + next.d * self.d,
+            tx: next.a * self.tx + next.b * self.ty + next.tx,
+            ty: next.c * self.tx + next.d * self.ty + next.ty,
+        }
+    }
+
+    /// Applies this [`Affine2`] to the provided [`Point`].
+    ///
+    /// Coordinates are rounded to the nearest integer, with halves rounded
+    /// away from zero, and saturate at the [`i32`] bounds.
+    #[must_use]
+    pub fn transform_point(&self, p: Point) -> Point {
+        let (x, y) = (f64::from(p.x), f64::from(p.y));
+        Point {
+            x: (self.a * x + self.b * y + self.tx).round() as i32,
+            y: (self.c * x + self.d * y + self.ty).round() as i32,
+        }
+    }
+}
+
+impl Default for Affine2 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_6};
+
+    use super::*;
+
+    mod transform_point {
+        use super::*;
+
+        #[test]
+        fn keeps_point_with_identity() {
+            let p = Point { x: -3, y: 7 };
+
+            assert_eq!(Affine2::IDENTITY.transform_point(p), p);
+            assert_eq!(Affine2::default(), Affine2::IDENTITY);
+        }
+
+        #[test]
+        fn translates() {
+            let t = Affine2::translation(Point { x: 10, y: -20 });
+
+            assert_eq!(
+                t.transform_point(Point { x: 1, y: 2 }),
+                Point { x: 11, y: -18 },
+            );
+        }
+
+        #[test]
+        fn scales_rounding_halves_away_from_zero() {
+            let s = Affine2::scaling(0.5, 3.0);
+
+            assert_eq!(
+                s.transform_point(Point { x: -3, y: 2 }),
+                Point { x: -2, y: 6 },
+            );
+        }
+
+        #[test]
+        fn rotates_counter_clockwise() {
+            let r = Affine2::rotation(FRAC_PI_2);
+
+            assert_eq!(
+                r.transform_point(Point { x: 3, y: 4 }),
+                Point { x: 3, y: 4 }.rotate_90_ccw(),
+            );
+        }
+    }
+
+    mod then {
+        use super::*;
+
+        #[test]
+        fn rotates_then_translates() {
+            let offset = Point { x: 10, y: 20 };
+            let t = Affine2::rotation(FRAC_PI_6).then(Affine2::translation(offset));
+
+            for p in [
+                Point { x: 0, y: 0 },
+                Point { x: 100, y: 0 },
+                Point { x: -40, y: 70 },
+            ] {
+                let (x, y) = (f64::from(p.x), f64::from(p.y));
+                let (sin, cos) = FRAC_PI_6.sin_cos();
+                let expected = Point {
+                    x: (x * cos - y * sin).round() as i32 + offset.x,
+                    y: (x * sin + y * cos).round() as i32 + offset.y,
+                };
+
+                assert_eq!(t.transform_point(p), expected, "for {p}");
+            }
+        }
+
+        #[test]
+        fn is_order_sensitive() {
+            let r = Affine2::rotation(FRAC_PI_2);
+            let t = Affine2::translation(Point { x: 5, y: 0 });
+            let p = Point { x: 1, y: 0 };
+
+            assert_eq!(r.then(t).transform_point(p), Point { x: 5, y: 1 });
+            assert_eq!(t.then(r).transform_point(p), Point { x: 0, y: 6 });
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! Core [`Point`] geometry is `no_std`-compatible (only requiring `alloc`):
+//! disabling the default `std` feature drops the floating point helpers
+//! (except [`Point::distance_to()`], falling back to an in-crate square root),
+//! including the [`Affine2`] transformations, and the [`Polyline`] type.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+pub mod affine;
+pub mod point;
+#[cfg(feature = "std")]
+pub mod polyline;
+
+pub use self::point::{Point, PointParseError};
+#[cfg(feature = "std")]
+pub use self::{
+    affine::Affine2,
+    polyline::{Polyline, PolylineBuilder, PolylineError, PolylineSlice},
+};
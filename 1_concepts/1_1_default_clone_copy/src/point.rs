@@ -0,0 +1,1828 @@
+mod error;
+
+use alloc::string::String;
+use core::{
+    fmt,
+    num::{IntErrorKind, ParseIntError},
+    ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+pub use self::error::PointParseError;
+
+/// 2D point with coordinates of type `T` ([`i32`] by default).
+///
+/// [`Point`]s are ordered lexicographically, by `x` first and then by `y`.
+/// This ordering carries no geometric meaning, and is only meant for sorting
+/// them deterministically (e.g. before computing a convex hull).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point<T = i32> {
+    pub x: T,
+    pub y: T,
+}
+
+impl Point {
+    /// [`Point`] at the origin of coordinates.
+    pub const ORIGIN: Self = Self { x: 0, y: 0 };
+
+    /// Unit [`Point`] along the `x` axis.
+    #[must_use]
+    pub const fn unit_x() -> Self {
+        Self { x: 1, y: 0 }
+    }
+
+    /// Unit [`Point`] along the `y` axis.
+    #[must_use]
+    pub const fn unit_y() -> Self {
+        Self { x: 0, y: 1 }
+    }
+
+    /// Returns a copy of this [`Point`] with the `x` coordinate replaced.
+    #[must_use]
+    pub const fn with_x(self, x: i32) -> Self {
+        Self { x, ..self }
+    }
+
+    /// Returns a copy of this [`Point`] with the `y` coordinate replaced.
+    #[must_use]
+    pub const fn with_y(self, y: i32) -> Self {
+        Self { y, ..self }
+    }
+
+    /// Returns the 4 lattice points adjacent to this [`Point`] along the axes,
+    /// as `[up, down, left, right]` ones, assuming the `y` axis points up.
+    ///
+    /// Coordinates overflow at the [`i32`] bounds just like `+` does.
+    #[must_use]
+    pub const fn neighbors4(self) -> [Self; 4] {
+        let Self { x, y } = self;
+        [
+            Self { x, y: y + 1 },
+            Self { x, y: y - 1 },
+            Self { x: x - 1, y },
+            Self { x: x + 1, y },
+        ]
+    }
+
+    /// Returns the 8 lattice points adjacent to this [`Point`], including the
+    /// diagonal ones: the [`Point::neighbors4()`] first, followed by the
+    /// `[up-left, up-right, down-left, down-right]` ones, assuming the `y` axis
+    /// points up.
+    ///
+    /// Coordinates overflow at the [`i32`] bounds just like `+` does.
+    #[must_use]
+    pub const fn neighbors8(self) -> [Self; 8] {
+        let [up, down, left, right] = self.neighbors4();
+        let Self { x, y } = self;
+        [
+            up,
+            down,
+            left,
+            right,
+            Self { x: x - 1, y: y + 1 },
+            Self { x: x + 1, y: y + 1 },
+            Self { x: x - 1, y: y - 1 },
+            Self { x: x + 1, y: y - 1 },
+        ]
+    }
+
+    /// Squared Euclidean distance to the `other` [`Point`].
+    ///
+    /// Computed in [`i64`], so it stays exact and can be used for comparisons
+    /// without floating point. It can only overflow when a coordinate delta
+    /// exceeds [`i32::MAX`] in magnitude, i.e. for points lying on opposite
+    /// ends of the [`i32`] range.
+    #[must_use]
+    pub fn squared_distance_to(self, other: Self) -> i64 {
+        let dx = i64::from(other.x) - i64::from(self.x);
+        let dy = i64::from(other.y) - i64::from(self.y);
+        dx * dx + dy * dy
+    }
+
+    /// Euclidean distance to the `other` [`Point`].
+    ///
+    /// Without the `std` feature, the square root is taken by an in-crate
+    /// fallback instead of [`f64::hypot()`], which may differ from it by an
+    /// ulp.
+    #[must_use]
+    pub fn distance_to(self, other: Self) -> f64 {
+        let dx = f64::from(other.x) - f64::from(self.x);
+        let dy = f64::from(other.y) - f64::from(self.y);
+        #[cfg(feature = "std")]
+        {
+            dx.hypot(dy)
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            sqrt(dx * dx + dy * dy)
+        }
+    }
+
+    /// Manhattan (L1) distance to the `other` [`Point`].
+    ///
+    /// Computed in [`i64`], so it never overflows.
+    #[must_use]
+    pub fn manhattan_distance_to(self, other: Self) -> i64 {
+        let dx = i64::from(other.x) - i64::from(self.x);
+        let dy = i64::from(other.y) - i64::from(self.y);
+        dx.abs() + dy.abs()
+    }
+
+    /// Dot product with the `other` [`Point`], both treated as vectors from
+    /// the origin.
+    ///
+    /// Computed in [`i64`], so it can only overflow when all four coordinates
+    /// are [`i32::MIN`].
+    #[must_use]
+    pub const fn dot(self, other: Self) -> i64 {
+        self.x as i64 * other.x as i64 + self.y as i64 * other.y as i64
+    }
+
+    /// Cross product (the `z` component of the 3D one) with the `other`
+    /// [`Point`], both treated as vectors from the origin.
+    ///
+    /// Positive if the `other` vector lies counter-clockwise from this one,
+    /// negative if clockwise, and zero if they are collinear, assuming the
+    /// `y` axis points up. Computed in [`i64`], so it never overflows.
+    #[must_use]
+    pub const fn cross(self, other: Self) -> i64 {
+        self.x as i64 * other.y as i64 - self.y as i64 * other.x as i64
+    }
+
+    /// Linearly interpolates between this [`Point`] (at `t = 0`) and the
+    /// `other` one (at `t = 1`).
+    ///
+    /// The `t` is clamped into `0.0..=1.0` range, so it never extrapolates.
+    /// Coordinates are rounded to the nearest integer, with halves rounded
+    /// away from zero (so the midpoint of `(0,0)` and `(3,3)` is `(2,2)`).
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn lerp(self, other: Self, t: f64) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let lerp = |a: i32, b: i32| {
+            let (a, b) = (f64::from(a), f64::from(b));
+            (a + (b - a) * t).round() as i32
+        };
+        Self {
+            x: lerp(self.x, other.x),
+            y: lerp(self.y, other.y),
+        }
+    }
+
+    /// Exports this [`Point`] as a canonical JSON object, like
+    /// `{"x":1,"y":2}`, without pulling any dependencies.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn to_json(self) -> String {
+        format!(r#"{{"x":{},"y":{}}}"#, self.x, self.y)
+    }
+
+    /// Per-axis minimum of this [`Point`] and the `other` one.
+    ///
+    /// Unlike the lexicographic [`Ord::min()`], each axis is compared
+    /// independently, so the result may be neither of the two [`Point`]s.
+    #[must_use]
+    pub fn component_min(self, other: Self) -> Self {
+        Self {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+        }
+    }
+
+    /// Per-axis maximum of this [`Point`] and the `other` one.
+    ///
+    /// Unlike the lexicographic [`Ord::max()`], each axis is compared
+    /// independently, so the result may be neither of the two [`Point`]s.
+    #[must_use]
+    pub fn component_max(self, other: Self) -> Self {
+        Self {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+        }
+    }
+
+    /// Clamps this [`Point`] into the axis-aligned box between the `min` and
+    /// `max` corners, each axis independently.
+    ///
+    /// Expects `min.x <= max.x` and `min.y <= max.y`, which is checked in
+    /// debug builds only.
+    #[must_use]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        debug_assert!(
+            min.x <= max.x && min.y <= max.y,
+            "`min` must not exceed `max` on any axis",
+        );
+        self.component_max(min).component_min(max)
+    }
+
+    /// Checked [`Point`] addition, returning [`None`] if any coordinate
+    /// overflows.
+    #[must_use]
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        match (self.x.checked_add(other.x), self.y.checked_add(other.y)) {
+            (Some(x), Some(y)) => Some(Self { x, y }),
+            _ => None,
+        }
+    }
+
+    /// Checked [`Point`] subtraction, returning [`None`] if any coordinate
+    /// overflows.
+    #[must_use]
+    pub const fn checked_sub(self, other: Self) -> Option<Self> {
+        match (self.x.checked_sub(other.x), self.y.checked_sub(other.y)) {
+            (Some(x), Some(y)) => Some(Self { x, y }),
+            _ => None,
+        }
+    }
+
+    /// Saturating [`Point`] addition, clamping each coordinate at the [`i32`]
+    /// bounds instead of overflowing.
+    #[must_use]
+    pub const fn saturating_add(self, other: Self) -> Self {
+        Self {
+            x: self.x.saturating_add(other.x),
+            y: self.y.saturating_add(other.y),
+        }
+    }
+
+    /// Saturating [`Point`] subtraction, clamping each coordinate at the
+    /// [`i32`] bounds instead of overflowing.
+    #[must_use]
+    pub const fn saturating_sub(self, other: Self) -> Self {
+        Self {
+            x: self.x.saturating_sub(other.x),
+            y: self.y.saturating_sub(other.y),
+        }
+    }
+
+    /// Per-axis absolute value of this [`Point`].
+    ///
+    /// A coordinate of [`i32::MIN`] saturates to [`i32::MAX`], as its absolute
+    /// value doesn't fit into [`i32`].
+    #[must_use]
+    pub const fn abs(self) -> Self {
+        Self {
+            x: self.x.saturating_abs(),
+            y: self.y.saturating_abs(),
+        }
+    }
+
+    /// Per-axis sign of this [`Point`]: `-1` for negative coordinates, `0` for
+    /// zero ones and `1` for positive ones, so it moves a single grid step
+    /// towards the direction of this [`Point`].
+    #[must_use]
+    pub const fn signum(self) -> Self {
+        Self {
+            x: self.x.signum(),
+            y: self.y.signum(),
+        }
+    }
+
+    /// Snaps this [`Point`] to the nearest node of the square grid with the
+    /// provided `cell` size, rounding each coordinate to the nearest multiple
+    /// of the `cell`, with halves rounded up.
+    ///
+    /// Expects a positive `cell`, which is checked in debug builds only.
+    ///
+    /// # Panics
+    ///
+    /// If the snapped coordinate doesn't fit into [`i32`].
+    #[must_use]
+    pub fn snap_to_grid(self, cell: i32) -> Self {
+        debug_assert!(cell > 0, "grid `cell` must be positive");
+
+        let snap = |v: i32| {
+            let (v, cell) = (i64::from(v), i64::from(cell));
+            i32::try_from((v + cell / 2).div_euclid(cell) * cell)
+                .expect("snapped coordinate overflows `i32`")
+        };
+        Self {
+            x: snap(self.x),
+            y: snap(self.y),
+        }
+    }
+
+    /// Indices `(column, row)` of the square grid cell with the provided `cell`
+    /// size containing this [`Point`], so that all the points within the same
+    /// `cell × cell` square share them.
+    ///
+    /// Coordinates are floored to the cell (so `-1` lies in the cell `-1`, not
+    /// `0`), unlike the rounding of [`Point::snap_to_grid()`].
+    ///
+    /// Expects a positive `cell`, which is checked in debug builds only.
+    #[must_use]
+    pub const fn spatial_bucket(self, cell: i32) -> (i32, i32) {
+        debug_assert!(cell > 0, "grid `cell` must be positive");
+
+        (self.x.div_euclid(cell), self.y.div_euclid(cell))
+    }
+
+    /// Angle (in radians) of the vector from this [`Point`] to the `other`
+    /// one, counted counter-clockwise from the positive `x` axis and
+    /// normalized into the `[0, 2π)` range.
+    ///
+    /// Returns `0.0` if the points coincide.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn angle_to(self, other: Self) -> f64 {
+        let dx = f64::from(other.x) - f64::from(self.x);
+        let dy = f64::from(other.y) - f64::from(self.y);
+        let angle = dy.atan2(dx);
+        if angle < 0.0 {
+            angle + core::f64::consts::TAU
+        } else {
+            angle
+        }
+    }
+
+    /// Wraps this [`Point`] around the torus of the provided `width` and
+    /// `height`, mapping its coordinates into the `[0, width)` and
+    /// `[0, height)` ranges respectively (so negative ones wrap from the
+    /// opposite edge).
+    ///
+    /// Expects positive `width` and `height`, which is checked in debug builds
+    /// only.
+    #[must_use]
+    pub const fn wrap(self, width: i32, height: i32) -> Self {
+        debug_assert!(
+            width > 0 && height > 0,
+            "torus `width` and `height` must be positive",
+        );
+        Self {
+            x: self.x.rem_euclid(width),
+            y: self.y.rem_euclid(height),
+        }
+    }
+
+    /// Creates a new [`Point`] from the provided [`i16`] coordinates (like the
+    /// ones of compact tile formats), which always fit.
+    #[must_use]
+    pub const fn from_i16(x: i16, y: i16) -> Self {
+        Self {
+            x: x as i32,
+            y: y as i32,
+        }
+    }
+
+    /// Narrows the coordinates of this [`Point`] into [`i16`] ones.
+    ///
+    /// Returns [`None`] if any of them doesn't fit into [`i16`].
+    #[must_use]
+    pub fn to_i16(self) -> Option<(i16, i16)> {
+        Some((i16::try_from(self.x).ok()?, i16::try_from(self.y).ok()?))
+    }
+
+    /// Packs this [`Point`] into a [`u64`], having the bits of `x` in its high
+    /// half and the bits of `y` in its low half (both bit-cast to [`u32`]).
+    ///
+    /// This layout is stable, so packed [`Point`]s may be stored and
+    /// exchanged.
+    #[must_use]
+    pub const fn to_packed(self) -> u64 {
+        ((self.x as u32 as u64) << 32) | self.y as u32 as u64
+    }
+
+    /// Unpacks a [`Point`] packed with [`Point::to_packed()`].
+    #[must_use]
+    pub const fn from_packed(packed: u64) -> Self {
+        Self {
+            x: (packed >> 32) as u32 as i32,
+            y: packed as u32 as i32,
+        }
+    }
+
+    /// Encodes this [`Point`] as the 16 lowercase hexadecimal digits of its
+    /// [`Point::to_packed()`] form, like `00000001fffffffe` for `(1, -2)`.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn to_hex(self) -> String {
+        format!("{:016x}", self.to_packed())
+    }
+
+    /// Decodes a [`Point`] encoded with [`Point::to_hex()`], accepting digits
+    /// of either case.
+    ///
+    /// # Errors
+    ///
+    /// - [`PointParseError::Empty`] if the `hex` is empty.
+    /// - [`PointParseError::NotANumber`] if the `hex` is not exactly 16
+    ///   hexadecimal digits.
+    pub fn from_hex(hex: &str) -> Result<Self, PointParseError> {
+        if hex.is_empty() {
+            return Err(PointParseError::Empty);
+        }
+        let invalid = || PointParseError::NotANumber(String::from(hex));
+        if hex.len() != 16 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+        u64::from_str_radix(hex, 16)
+            .map(Self::from_packed)
+            .map_err(|_| invalid())
+    }
+
+    /// Midpoint between this [`Point`] and the `other` one.
+    ///
+    /// Coordinates are summed in [`i64`], so this never overflows, and are
+    /// rounded to the nearest integer, with halves rounded away from zero
+    /// (same as [`Point::lerp`] does).
+    #[must_use]
+    pub fn midpoint(self, other: Self) -> Self {
+        let mid = |a: i32, b: i32| {
+            let sum = i64::from(a) + i64::from(b);
+            // Halved sum of two `i32`s always lies within the `i32` range.
+            ((sum + sum.signum()) / 2) as i32
+        };
+        Self {
+            x: mid(self.x, other.x),
+            y: mid(self.y, other.y),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Point<f64> {
+    /// Euclidean distance to the `other` [`Point`].
+    #[must_use]
+    pub fn distance_to(self, other: Self) -> f64 {
+        (other.x - self.x).hypot(other.y - self.y)
+    }
+}
+
+/// Exact rotations around the origin, assuming the `y` axis points up.
+impl<T: Neg<Output = T>> Point<T> {
+    /// Rotates this [`Point`] by 90° clockwise.
+    #[must_use]
+    pub fn rotate_90_cw(self) -> Self {
+        Self {
+            x: self.y,
+            y: -self.x,
+        }
+    }
+
+    /// Rotates this [`Point`] by 90° counter-clockwise.
+    #[must_use]
+    pub fn rotate_90_ccw(self) -> Self {
+        Self {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    /// Rotates this [`Point`] by 180°.
+    #[must_use]
+    pub fn rotate_180(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<[T; 2]> for Point<T> {
+    fn from([x, y]: [T; 2]) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(p: Point<T>) -> Self {
+        (p.x, p.y)
+    }
+}
+
+impl<T> From<Point<T>> for [T; 2] {
+    fn from(p: Point<T>) -> Self {
+        [p.x, p.y]
+    }
+}
+
+/// Renders this [`Point`] as `(x, y)`.
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl FromStr for Point {
+    type Err = PointParseError;
+
+    /// Parses a [`Point`] from its `"x,y"` form, allowing whitespace around
+    /// each coordinate (like `"3, 4"`).
+    ///
+    /// Anything after the first comma is parsed as the `y` coordinate, so an
+    /// extra component (like in `"3,4,5"`) makes it [`NotANumber`].
+    ///
+    /// [`NotANumber`]: PointParseError::NotANumber
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Err(PointParseError::Empty);
+        }
+        let (x, y) = s.split_once(',').ok_or(PointParseError::MissingComponent)?;
+        Ok(Self {
+            x: parse_coordinate(x.trim())?,
+            y: parse_coordinate(y.trim())?,
+        })
+    }
+}
+
+/// Square root of the provided non-negative `v`, for the `no_std` builds
+/// lacking [`f64::sqrt()`], found by the Newton–Raphson iterations starting
+/// from the bit-level estimate of halving its exponent.
+///
+/// For normal `v` (as the squared distances between integer [`Point`]s are),
+/// the estimate is within 6% of the root, and each iteration doubles the
+/// number of correct bits, so 6 of them converge to within an ulp, while
+/// perfect squares are reached exactly.
+#[cfg(any(test, not(feature = "std")))]
+fn sqrt(v: f64) -> f64 {
+    if v.is_nan() || v < 0.0 {
+        return f64::NAN;
+    }
+    if v == 0.0 || v == f64::INFINITY {
+        return v;
+    }
+    let mut root = f64::from_bits((v.to_bits() >> 1) + (1023 << 51));
+    for _ in 0..6 {
+        root = 0.5 * (root + v / root);
+    }
+    root
+}
+
+/// Parses a single [`i32`] coordinate from the provided `token`, being
+/// [`PointParseError::MissingComponent`] if it's empty.
+pub(crate) fn parse_coordinate(token: &str) -> Result<i32, PointParseError> {
+    if token.is_empty() {
+        return Err(PointParseError::MissingComponent);
+    }
+    token.parse().map_err(|e: ParseIntError| match e.kind() {
+        IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => PointParseError::OutOfRange,
+        _ => PointParseError::NotANumber(String::from(token)),
+    })
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+/// Divides both coordinates, rounding toward zero for integers like [`i32`]
+/// division does.
+impl<T: Div<Output = T> + Copy> Div<T> for Point<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self {
+        Self {
+            x: self.x / rhs,
+            y: self.y / rhs,
+        }
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+/// Implements the by-reference variants of a binary operator by forwarding to
+/// the by-value one, the same way `std` does for primitives.
+macro_rules! forward_ref_binop {
+    ($trait:ident, $method:ident, $rhs:ty) => {
+        impl<T: $trait<Output = T> + Copy> $trait<$rhs> for &Point<T> {
+            type Output = Point<T>;
+
+            fn $method(self, rhs: $rhs) -> Point<T> {
+                (*self).$method(rhs)
+            }
+        }
+
+        impl<T: $trait<Output = T> + Copy> $trait<&$rhs> for Point<T> {
+            type Output = Point<T>;
+
+            fn $method(self, rhs: &$rhs) -> Point<T> {
+                self.$method(*rhs)
+            }
+        }
+
+        impl<T: $trait<Output = T> + Copy> $trait<&$rhs> for &Point<T> {
+            type Output = Point<T>;
+
+            fn $method(self, rhs: &$rhs) -> Point<T> {
+                (*self).$method(*rhs)
+            }
+        }
+    };
+}
+
+forward_ref_binop!(Add, add, Point<T>);
+forward_ref_binop!(Sub, sub, Point<T>);
+forward_ref_binop!(Mul, mul, T);
+forward_ref_binop!(Div, div, T);
+
+impl<T: Neg<Output = T> + Copy> Neg for &Point<T> {
+    type Output = Point<T>;
+
+    fn neg(self) -> Point<T> {
+        -*self
+    }
+}
+
+impl<T: AddAssign + Copy> AddAssign<&Point<T>> for Point<T> {
+    fn add_assign(&mut self, rhs: &Point<T>) {
+        *self += *rhs;
+    }
+}
+
+impl<T: SubAssign + Copy> SubAssign<&Point<T>> for Point<T> {
+    fn sub_assign(&mut self, rhs: &Point<T>) {
+        *self -= *rhs;
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use super::*;
+
+    fn xy(p: Point) -> (i32, i32) {
+        (p.x, p.y)
+    }
+
+    mod ops {
+        use super::*;
+
+        #[test]
+        fn adds_and_subtracts_per_axis() {
+            let a = Point { x: 1, y: 2 };
+            let b = Point { x: 10, y: -20 };
+
+            assert_eq!(xy(a + b), (11, -18));
+            assert_eq!(xy(a - b), (-9, 22));
+
+            let (a, b) = (&a, &b);
+            assert_eq!(xy(a + b), (11, -18));
+            assert_eq!(xy(*a - b), (-9, 22));
+        }
+
+        #[test]
+        fn negates_both_coordinates() {
+            assert_eq!(xy(-Point { x: 3, y: -4 }), (-3, 4));
+            assert_eq!(xy(-&Point { x: 3, y: -4 }), (-3, 4));
+        }
+
+        #[test]
+        fn scales_by_integer() {
+            let p = Point { x: 3, y: -4 };
+
+            assert_eq!(xy(p * 2), (6, -8));
+
+            let p = &p;
+            assert_eq!(xy(p * -1), (-3, 4));
+        }
+
+        #[test]
+        fn divides_rounding_toward_zero() {
+            let p = Point { x: 7, y: -7 };
+
+            assert_eq!(xy(p / 2), (3, -3));
+
+            let p = &p;
+            assert_eq!(xy(p / -2), (-3, 3));
+        }
+
+        #[test]
+        fn assigns_in_place() {
+            let mut p = Point { x: 1, y: 1 };
+
+            p += Point { x: 2, y: 3 };
+            assert_eq!(xy(p), (3, 4));
+
+            p -= &Point { x: 3, y: 4 };
+            assert_eq!(xy(p), (0, 0));
+        }
+    }
+
+    mod distance {
+        use super::*;
+
+        #[test]
+        fn measures_pythagorean_triple() {
+            let a = Point { x: 1, y: 1 };
+            let b = Point { x: 4, y: 5 };
+
+            assert_eq!(a.squared_distance_to(b), 25);
+            assert_eq!(b.squared_distance_to(a), 25);
+            assert_eq!(a.distance_to(b), 5.0);
+        }
+
+        #[test]
+        fn is_zero_to_itself() {
+            let p = Point { x: -7, y: 3 };
+
+            assert_eq!(p.squared_distance_to(p), 0);
+            assert_eq!(p.distance_to(p), 0.0);
+        }
+
+        #[cfg(feature = "std")]
+        #[test]
+        fn does_not_overflow_near_i32_max() {
+            let a = Point::default();
+            let b = Point {
+                x: i32::MAX,
+                y: i32::MAX,
+            };
+            let max = i64::from(i32::MAX);
+
+            assert_eq!(a.squared_distance_to(b), 2 * max * max);
+            assert_eq!(a.squared_distance_to(-b), 2 * max * max);
+            assert!((a.distance_to(b) - (max as f64) * 2f64.sqrt()).abs() < 1e-3);
+        }
+
+        #[test]
+        fn measures_manhattan_per_axis() {
+            let a = Point { x: 1, y: -1 };
+            let b = Point { x: -2, y: 3 };
+
+            assert_eq!(a.manhattan_distance_to(b), 7);
+            assert_eq!(b.manhattan_distance_to(a), 7);
+            assert_eq!(a.manhattan_distance_to(a), 0);
+        }
+
+        #[test]
+        fn does_not_overflow_manhattan_on_i32_extremes() {
+            let min = Point {
+                x: i32::MIN,
+                y: i32::MIN,
+            };
+            let max = Point {
+                x: i32::MAX,
+                y: i32::MAX,
+            };
+            let span = i64::from(i32::MAX) - i64::from(i32::MIN);
+
+            assert_eq!(min.manhattan_distance_to(max), 2 * span);
+            assert_eq!(max.manhattan_distance_to(min), 2 * span);
+        }
+    }
+
+    mod eq {
+        use super::*;
+
+        #[test]
+        fn compares_both_coordinates() {
+            assert_eq!(Point { x: 1, y: 2 }, Point { x: 1, y: 2 });
+            assert_ne!(Point { x: 1, y: 2 }, Point { x: 2, y: 1 });
+            assert_eq!(Point::default(), Point { x: 0, y: 0 });
+        }
+    }
+
+    mod ord {
+        use super::*;
+
+        #[test]
+        fn compares_x_first() {
+            assert!(Point { x: 1, y: 9 } < Point { x: 2, y: -9 });
+            assert!(Point { x: 1, y: 2 } < Point { x: 1, y: 3 });
+        }
+
+        #[test]
+        fn sorts_lexicographically() {
+            let mut points = [
+                Point { x: 2, y: 0 },
+                Point { x: -1, y: 5 },
+                Point { x: 2, y: -3 },
+                Point { x: 0, y: 0 },
+                Point { x: -1, y: -5 },
+            ];
+
+            points.sort_unstable();
+
+            assert_eq!(
+                points,
+                [
+                    Point { x: -1, y: -5 },
+                    Point { x: -1, y: 5 },
+                    Point { x: 0, y: 0 },
+                    Point { x: 2, y: -3 },
+                    Point { x: 2, y: 0 },
+                ],
+            );
+        }
+    }
+
+    mod convert {
+        use super::*;
+
+        #[test]
+        fn builds_from_x_y_tuple() {
+            assert_eq!(Point::from((3, 4)), Point { x: 3, y: 4 });
+        }
+
+        #[test]
+        fn builds_from_x_y_array() {
+            assert_eq!(Point::from([3, 4]), Point { x: 3, y: 4 });
+        }
+
+        #[test]
+        fn converts_into_x_y_tuple() {
+            let (x, y) = Point { x: 3, y: 4 }.into();
+
+            assert_eq!((x, y), (3, 4));
+        }
+
+        #[test]
+        fn converts_into_x_y_array() {
+            let [x, y]: [i32; 2] = Point { x: 3, y: 4 }.into();
+
+            assert_eq!([x, y], [3, 4]);
+        }
+    }
+
+    mod generic {
+        use super::*;
+
+        #[cfg(feature = "std")]
+        #[test]
+        fn measures_float_distance() {
+            let a = Point { x: 0.5, y: 0.5 };
+            let b = Point { x: 1.5, y: 1.5 };
+
+            assert_eq!(a.distance_to(b), 2f64.sqrt());
+        }
+
+        #[test]
+        fn applies_float_arithmetic() {
+            let p = Point { x: 1.5, y: -2.0 };
+
+            assert_eq!(p + p, Point { x: 3.0, y: -4.0 });
+            assert_eq!(-p * 2.0, Point { x: -3.0, y: 4.0 });
+            assert_eq!(p / 2.0, Point { x: 0.75, y: -1.0 });
+        }
+
+        #[cfg(feature = "std")]
+        #[test]
+        fn defaults_to_i32_coordinates() {
+            let p: Point = Point::from((3, 4));
+            let q: Point<i32> = p;
+
+            assert_eq!(p.distance_to(Point::default()), 5.0);
+            assert_eq!(q.squared_distance_to(Point::default()), 25);
+        }
+    }
+
+    mod rotate {
+        use super::*;
+
+        #[test]
+        fn rotates_unit_x_by_quarter_turns() {
+            let p = Point { x: 1, y: 0 };
+
+            assert_eq!(p.rotate_90_cw(), Point { x: 0, y: -1 });
+            assert_eq!(p.rotate_90_ccw(), Point { x: 0, y: 1 });
+            assert_eq!(p.rotate_180(), Point { x: -1, y: 0 });
+        }
+
+        #[test]
+        fn returns_to_original_after_four_cw_rotations() {
+            let p = Point { x: 3, y: -7 };
+
+            assert_eq!(
+                p.rotate_90_cw()
+                    .rotate_90_cw()
+                    .rotate_90_cw()
+                    .rotate_90_cw(),
+                p,
+            );
+            assert_eq!(p.rotate_90_cw().rotate_90_ccw(), p);
+        }
+
+        #[test]
+        fn rotates_180_as_negation() {
+            let p = Point { x: 3, y: -7 };
+
+            assert_eq!(p.rotate_180(), -p);
+            assert_eq!(p.rotate_90_cw().rotate_90_cw(), -p);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod lerp {
+        use super::*;
+
+        const A: Point = Point { x: 0, y: 0 };
+        const B: Point = Point { x: 3, y: 3 };
+
+        #[test]
+        fn returns_endpoints() {
+            assert_eq!(A.lerp(B, 0.0), A);
+            assert_eq!(A.lerp(B, 1.0), B);
+        }
+
+        #[test]
+        fn rounds_midpoint_half_away_from_zero() {
+            assert_eq!(A.lerp(B, 0.5), Point { x: 2, y: 2 });
+            assert_eq!(A.lerp(-B, 0.5), Point { x: -2, y: -2 });
+        }
+
+        #[test]
+        fn interpolates_each_axis() {
+            let p = Point { x: -10, y: 10 }.lerp(Point { x: 10, y: 30 }, 0.25);
+
+            assert_eq!(p, Point { x: -5, y: 15 });
+        }
+
+        #[test]
+        fn clamps_parameter() {
+            assert_eq!(A.lerp(B, -1.0), A);
+            assert_eq!(A.lerp(B, 2.0), B);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod to_json {
+        use super::*;
+
+        #[test]
+        fn renders_canonical_object() {
+            assert_eq!(Point { x: 1, y: -2 }.to_json(), r#"{"x":1,"y":-2}"#);
+            assert_eq!(Point::default().to_json(), r#"{"x":0,"y":0}"#);
+        }
+    }
+
+    mod component_min_max {
+        use super::*;
+
+        #[test]
+        fn compares_axes_independently() {
+            let a = Point { x: 1, y: 8 };
+            let b = Point { x: 5, y: -2 };
+
+            assert_eq!(a.component_min(b), Point { x: 1, y: -2 });
+            assert_eq!(a.component_max(b), Point { x: 5, y: 8 });
+            assert_eq!(b.component_min(a), a.component_min(b));
+        }
+
+        #[test]
+        fn differs_from_lexicographic_ord() {
+            let a = Point { x: 1, y: 8 };
+            let b = Point { x: 5, y: -2 };
+
+            assert_eq!(a.min(b), a);
+            assert_eq!(a.max(b), b);
+            assert_ne!(a.component_min(b), a.min(b));
+        }
+
+        #[test]
+        fn chains_min_on_x_with_max_on_y() {
+            let p = Point { x: 3, y: 3 }
+                .component_min(Point { x: -1, y: 100 })
+                .component_max(Point { x: -100, y: 7 });
+
+            assert_eq!(p, Point { x: -1, y: 7 });
+        }
+    }
+
+    mod clamp {
+        use super::*;
+
+        const MIN: Point = Point { x: -2, y: 0 };
+        const MAX: Point = Point { x: 4, y: 3 };
+
+        #[test]
+        fn keeps_point_inside() {
+            assert_eq!(Point { x: 1, y: 2 }.clamp(MIN, MAX), Point { x: 1, y: 2 });
+            assert_eq!(MIN.clamp(MIN, MAX), MIN);
+            assert_eq!(MAX.clamp(MIN, MAX), MAX);
+        }
+
+        #[test]
+        fn clamps_single_axis() {
+            assert_eq!(Point { x: 9, y: 1 }.clamp(MIN, MAX), Point { x: 4, y: 1 });
+            assert_eq!(Point { x: 0, y: -5 }.clamp(MIN, MAX), Point { x: 0, y: 0 });
+        }
+
+        #[test]
+        fn clamps_both_axes() {
+            assert_eq!(Point { x: -9, y: 7 }.clamp(MIN, MAX), Point { x: -2, y: 3 });
+            assert_eq!(Point { x: 5, y: -1 }.clamp(MIN, MAX), Point { x: 4, y: 0 });
+        }
+
+        #[cfg(debug_assertions)]
+        #[test]
+        #[should_panic = "`min` must not exceed `max` on any axis"]
+        fn panics_on_inverted_bounds_in_debug() {
+            let _ = Point::default().clamp(MAX, MIN);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod display {
+        use super::*;
+
+        #[test]
+        fn renders_coordinates() {
+            assert_eq!(Point { x: 3, y: -4 }.to_string(), "(3, -4)");
+            assert_eq!(Point { x: 0.5, y: 2.0 }.to_string(), "(0.5, 2)");
+        }
+
+        #[test]
+        fn differs_from_debug() {
+            let p = Point { x: 1, y: 2 };
+
+            assert_eq!(format!("{p:?}"), "Point { x: 1, y: 2 }");
+            assert_eq!(format!("{p}"), "(1, 2)");
+        }
+    }
+
+    mod no_std {
+        use super::*;
+
+        #[test]
+        fn integer_methods_need_no_std() {
+            let a = Point { x: 1, y: 1 };
+            let b = Point { x: 4, y: 5 };
+
+            assert_eq!(a.squared_distance_to(b), 25);
+            assert_eq!(a.manhattan_distance_to(b), 7);
+            assert_eq!(xy(b - a), (3, 4));
+            assert_eq!(xy(b.rotate_90_cw()), (5, -4));
+            assert_eq!(xy(b.clamp(Point::default(), Point { x: 2, y: 2 })), (2, 2));
+            assert_eq!(a.distance_to(b), 5.0);
+        }
+    }
+
+    mod sqrt {
+        use super::*;
+
+        #[test]
+        fn finds_perfect_squares_exactly() {
+            for root in [1_u32, 2, 3, 12, 1_000, 65_535, u32::MAX] {
+                let root = f64::from(root);
+
+                assert_eq!(sqrt(root * root), root, "for {root}²");
+            }
+        }
+
+        #[test]
+        fn handles_special_values() {
+            assert_eq!(sqrt(0.0), 0.0);
+            assert_eq!(sqrt(f64::INFINITY), f64::INFINITY);
+            assert!(sqrt(-1.0).is_nan());
+            assert!(sqrt(f64::NAN).is_nan());
+        }
+
+        #[cfg(feature = "std")]
+        #[test]
+        fn matches_std_within_ulp() {
+            let mut v = f64::MIN_POSITIVE;
+            while v < 1e300 {
+                for v in [v, v * 1.7, v * 3.1] {
+                    let (ours, theirs) = (sqrt(v), v.sqrt());
+
+                    assert!(
+                        (ours - theirs).abs() <= f64::EPSILON * theirs,
+                        "{ours} differs from {theirs} for {v}",
+                    );
+                }
+                v *= 10.0;
+            }
+        }
+    }
+
+    mod from_str {
+        use super::*;
+
+        #[test]
+        fn parses_valid_input() {
+            assert_eq!("3,4".parse(), Ok(Point { x: 3, y: 4 }));
+            assert_eq!("3, 4".parse(), Ok(Point { x: 3, y: 4 }));
+            assert_eq!(" -3 ,\t+4 ".parse(), Ok(Point { x: -3, y: 4 }));
+        }
+
+        #[test]
+        fn errors_on_empty_input() {
+            assert_eq!("".parse::<Point>(), Err(PointParseError::Empty));
+            assert_eq!(" \t ".parse::<Point>(), Err(PointParseError::Empty));
+        }
+
+        #[test]
+        fn errors_on_missing_component() {
+            for s in ["3 4", "3", "3,", ",4", " , "] {
+                assert_eq!(
+                    s.parse::<Point>(),
+                    Err(PointParseError::MissingComponent),
+                    "for {s:?}",
+                );
+            }
+        }
+
+        #[test]
+        fn errors_on_extra_component() {
+            assert_eq!(
+                "3,4,".parse::<Point>(),
+                Err(PointParseError::NotANumber("4,".into())),
+            );
+            assert_eq!(
+                "3,4,5".parse::<Point>(),
+                Err(PointParseError::NotANumber("4,5".into())),
+            );
+        }
+
+        #[test]
+        fn errors_on_non_numeric_component() {
+            assert_eq!(
+                "3,four".parse::<Point>(),
+                Err(PointParseError::NotANumber("four".into())),
+            );
+            assert_eq!(
+                " 3.5 ,4".parse::<Point>(),
+                Err(PointParseError::NotANumber("3.5".into())),
+            );
+        }
+
+        #[test]
+        fn errors_on_out_of_range_component() {
+            assert_eq!(
+                "2147483648,0".parse::<Point>(),
+                Err(PointParseError::OutOfRange)
+            );
+            assert_eq!(
+                "0,-2147483649".parse::<Point>(),
+                Err(PointParseError::OutOfRange)
+            );
+            assert_eq!(
+                "2147483647,-2147483648".parse(),
+                Ok(Point {
+                    x: i32::MAX,
+                    y: i32::MIN,
+                }),
+            );
+        }
+    }
+
+    mod checked {
+        use super::*;
+
+        const MAX: Point = Point {
+            x: i32::MAX,
+            y: i32::MAX,
+        };
+        const ONE: Point = Point { x: 1, y: 1 };
+
+        #[test]
+        fn adds_within_range() {
+            assert_eq!(
+                Point {
+                    x: i32::MAX - 1,
+                    y: 0
+                }
+                .checked_add(ONE),
+                Some(Point { x: i32::MAX, y: 1 })
+            );
+            assert_eq!(MAX.checked_add(-MAX), Some(Point::default()));
+        }
+
+        #[test]
+        fn returns_none_on_add_overflow() {
+            assert_eq!(MAX.checked_add(ONE), None);
+            assert_eq!(Point { x: 0, y: i32::MAX }.checked_add(ONE), None);
+        }
+
+        #[test]
+        fn subtracts_within_range() {
+            assert_eq!(MAX.checked_sub(MAX), Some(Point::default()));
+            assert_eq!(
+                (-MAX).checked_sub(ONE),
+                Some(Point {
+                    x: i32::MIN,
+                    y: i32::MIN
+                })
+            );
+        }
+
+        #[test]
+        fn returns_none_on_sub_overflow() {
+            assert_eq!((-MAX).checked_sub(Point { x: 2, y: 0 }), None);
+            assert_eq!(MAX.checked_sub(Point { x: 0, y: -1 }), None);
+        }
+    }
+
+    mod saturating {
+        use super::*;
+
+        const MAX: Point = Point {
+            x: i32::MAX,
+            y: i32::MAX,
+        };
+        const MIN: Point = Point {
+            x: i32::MIN,
+            y: i32::MIN,
+        };
+        const ONE: Point = Point { x: 1, y: 1 };
+
+        #[test]
+        fn adds_within_range() {
+            assert_eq!((MAX - ONE).saturating_add(ONE), MAX);
+            assert_eq!(MAX.saturating_add(MIN), Point { x: -1, y: -1 });
+        }
+
+        #[test]
+        fn saturates_add_per_axis() {
+            assert_eq!(MAX.saturating_add(ONE), MAX);
+            assert_eq!(MIN.saturating_add(-ONE), MIN);
+            assert_eq!(
+                Point { x: i32::MAX, y: 0 }.saturating_add(Point { x: 5, y: -5 }),
+                Point { x: i32::MAX, y: -5 },
+            );
+            assert_eq!(
+                Point { x: 0, y: i32::MIN }.saturating_add(Point { x: 5, y: -5 }),
+                Point { x: 5, y: i32::MIN },
+            );
+        }
+
+        #[test]
+        fn subtracts_within_range() {
+            assert_eq!((MIN + ONE).saturating_sub(ONE), MIN);
+            assert_eq!(MAX.saturating_sub(MAX), Point::ORIGIN);
+        }
+
+        #[test]
+        fn saturates_sub_per_axis() {
+            assert_eq!(MIN.saturating_sub(ONE), MIN);
+            assert_eq!(MAX.saturating_sub(MIN), MAX);
+            assert_eq!(
+                Point { x: i32::MIN, y: 0 }.saturating_sub(Point { x: 1, y: 1 }),
+                Point { x: i32::MIN, y: -1 },
+            );
+            assert_eq!(
+                Point { x: 0, y: i32::MAX }.saturating_sub(Point { x: 1, y: -1 }),
+                Point { x: -1, y: i32::MAX },
+            );
+        }
+    }
+
+    mod snap_to_grid {
+        use super::*;
+
+        #[test]
+        fn keeps_point_on_grid() {
+            assert_eq!(
+                Point { x: 20, y: -30 }.snap_to_grid(10),
+                Point { x: 20, y: -30 }
+            );
+            assert_eq!(Point { x: 7, y: -7 }.snap_to_grid(1), Point { x: 7, y: -7 });
+        }
+
+        #[test]
+        fn rounds_to_nearest_cell() {
+            assert_eq!(
+                Point { x: 14, y: 16 }.snap_to_grid(10),
+                Point { x: 10, y: 20 }
+            );
+            assert_eq!(Point { x: 1, y: 2 }.snap_to_grid(3), Point { x: 0, y: 3 });
+        }
+
+        #[test]
+        fn rounds_halves_up() {
+            assert_eq!(
+                Point { x: 5, y: 15 }.snap_to_grid(10),
+                Point { x: 10, y: 20 }
+            );
+            assert_eq!(
+                Point { x: -5, y: -15 }.snap_to_grid(10),
+                Point { x: 0, y: -10 }
+            );
+        }
+
+        #[test]
+        fn rounds_negative_coordinates() {
+            assert_eq!(
+                Point { x: -14, y: -16 }.snap_to_grid(10),
+                Point { x: -10, y: -20 }
+            );
+            assert_eq!(
+                Point { x: -1, y: -2 }.snap_to_grid(3),
+                Point { x: 0, y: -3 }
+            );
+        }
+
+        #[cfg(debug_assertions)]
+        #[test]
+        #[should_panic = "grid `cell` must be positive"]
+        fn panics_on_non_positive_cell_in_debug() {
+            let _ = Point::default().snap_to_grid(0);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod angle_to {
+        use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+        use super::*;
+
+        const O: Point = Point { x: 0, y: 0 };
+
+        #[test]
+        fn points_in_cardinal_directions() {
+            assert_eq!(O.angle_to(Point { x: 5, y: 0 }), 0.0);
+            assert_eq!(O.angle_to(Point { x: 0, y: 5 }), FRAC_PI_2);
+            assert_eq!(O.angle_to(Point { x: -5, y: 0 }), PI);
+            assert_eq!(O.angle_to(Point { x: 0, y: -5 }), 3.0 * FRAC_PI_2);
+        }
+
+        #[test]
+        fn points_diagonally() {
+            assert_eq!(O.angle_to(Point { x: 3, y: 3 }), FRAC_PI_4);
+            assert!((O.angle_to(Point { x: 3, y: -3 }) - 7.0 * FRAC_PI_4).abs() < 1e-12);
+        }
+
+        #[test]
+        fn is_zero_to_itself() {
+            assert_eq!(O.angle_to(O), 0.0);
+        }
+    }
+
+    mod consts {
+        use super::*;
+
+        const CORNERS: [Point; 3] = [Point::ORIGIN, Point::unit_x(), Point::unit_y()];
+        const GRID: [Point; 4] = [Point::ORIGIN; 4];
+
+        #[test]
+        fn are_const_evaluable() {
+            assert_eq!(CORNERS[0], Point::default());
+            assert_eq!(GRID, [Point { x: 0, y: 0 }; 4]);
+        }
+
+        #[test]
+        fn have_expected_values() {
+            assert_eq!(Point::ORIGIN, Point { x: 0, y: 0 });
+            assert_eq!(Point::unit_x(), Point { x: 1, y: 0 });
+            assert_eq!(Point::unit_y(), Point { x: 0, y: 1 });
+            assert_eq!(Point::unit_x().rotate_90_ccw(), Point::unit_y());
+        }
+    }
+
+    mod wrap {
+        use super::*;
+
+        #[test]
+        fn keeps_point_in_range() {
+            assert_eq!(Point { x: 0, y: 0 }.wrap(10, 5), Point { x: 0, y: 0 });
+            assert_eq!(Point { x: 9, y: 4 }.wrap(10, 5), Point { x: 9, y: 4 });
+        }
+
+        #[test]
+        fn wraps_overflowing_coordinates() {
+            assert_eq!(Point { x: 10, y: 5 }.wrap(10, 5), Point { x: 0, y: 0 });
+            assert_eq!(Point { x: 23, y: 12 }.wrap(10, 5), Point { x: 3, y: 2 });
+        }
+
+        #[test]
+        fn wraps_negative_coordinates() {
+            assert_eq!(Point { x: -1, y: -1 }.wrap(10, 5), Point { x: 9, y: 4 });
+            assert_eq!(Point { x: -10, y: -7 }.wrap(10, 5), Point { x: 0, y: 3 });
+            assert_eq!(
+                Point { x: i32::MIN, y: 0 }.wrap(10, 5),
+                Point { x: 2, y: 0 }
+            );
+        }
+
+        #[cfg(debug_assertions)]
+        #[test]
+        #[should_panic = "torus `width` and `height` must be positive"]
+        fn panics_on_empty_torus_in_debug() {
+            let _ = Point::default().wrap(0, 5);
+        }
+    }
+
+    mod packed {
+        use super::*;
+
+        #[test]
+        fn places_x_in_high_half() {
+            assert_eq!(Point { x: 1, y: 2 }.to_packed(), 0x0000_0001_0000_0002);
+            assert_eq!(Point { x: -1, y: 0 }.to_packed(), 0xFFFF_FFFF_0000_0000);
+            assert_eq!(Point { x: 0, y: -1 }.to_packed(), 0x0000_0000_FFFF_FFFF);
+        }
+
+        #[test]
+        fn round_trips_with_sign() {
+            for p in [
+                Point { x: 0, y: 0 },
+                Point { x: 3, y: -4 },
+                Point { x: -3, y: 4 },
+                Point {
+                    x: i32::MIN,
+                    y: i32::MAX,
+                },
+                Point {
+                    x: i32::MAX,
+                    y: i32::MIN,
+                },
+            ] {
+                assert_eq!(Point::from_packed(p.to_packed()), p);
+            }
+        }
+    }
+
+    mod hex {
+        use super::*;
+
+        #[cfg(feature = "std")]
+        #[test]
+        fn encodes_packed_digits() {
+            assert_eq!(Point { x: 1, y: -2 }.to_hex(), "00000001fffffffe");
+            assert_eq!(Point::ORIGIN.to_hex(), "0000000000000000");
+        }
+
+        #[cfg(feature = "std")]
+        #[test]
+        fn round_trips_with_sign() {
+            for p in [
+                Point { x: 0, y: 0 },
+                Point { x: -3, y: 4 },
+                Point { x: 3, y: -4 },
+                Point {
+                    x: i32::MIN,
+                    y: i32::MAX,
+                },
+            ] {
+                assert_eq!(Point::from_hex(&p.to_hex()), Ok(p));
+            }
+        }
+
+        #[test]
+        fn decodes_either_case() {
+            assert_eq!(
+                Point::from_hex("FFFFFFFF0000000A"),
+                Ok(Point { x: -1, y: 10 }),
+            );
+            assert_eq!(
+                Point::from_hex("ffffffff0000000a"),
+                Ok(Point { x: -1, y: 10 }),
+            );
+        }
+
+        #[test]
+        fn errors_on_empty_hex() {
+            assert_eq!(Point::from_hex(""), Err(PointParseError::Empty));
+        }
+
+        #[test]
+        fn errors_on_malformed_hex() {
+            for hex in [
+                "0000000100000002 ",
+                "000000010000002",
+                "00000001000000021",
+                "+000000100000002",
+                "0000000g00000002",
+            ] {
+                assert_eq!(
+                    Point::from_hex(hex),
+                    Err(PointParseError::NotANumber(hex.into())),
+                    "for {hex:?}",
+                );
+            }
+        }
+    }
+
+    mod midpoint {
+        use super::*;
+
+        #[test]
+        fn averages_coordinates() {
+            assert_eq!(
+                Point { x: 0, y: 0 }.midpoint(Point { x: 4, y: -6 }),
+                Point { x: 2, y: -3 }
+            );
+            assert_eq!(
+                Point { x: 5, y: 5 }.midpoint(Point { x: 5, y: 5 }),
+                Point { x: 5, y: 5 }
+            );
+        }
+
+        #[test]
+        fn rounds_odd_sums_away_from_zero() {
+            assert_eq!(
+                Point { x: 0, y: 0 }.midpoint(Point { x: 3, y: -3 }),
+                Point { x: 2, y: -2 }
+            );
+            assert_eq!(
+                Point { x: -4, y: 4 }.midpoint(Point { x: 1, y: -1 }),
+                Point { x: -2, y: 2 }
+            );
+        }
+
+        #[test]
+        fn does_not_overflow_on_i32_extremes() {
+            let max = Point {
+                x: i32::MAX,
+                y: i32::MIN,
+            };
+
+            assert_eq!(max.midpoint(max), max);
+            assert_eq!(
+                max.midpoint(Point {
+                    x: i32::MIN,
+                    y: i32::MAX,
+                }),
+                Point { x: -1, y: -1 },
+            );
+        }
+    }
+
+    mod products {
+        use super::*;
+
+        #[test]
+        fn dot_is_zero_for_perpendicular() {
+            let v = Point { x: 3, y: -4 };
+
+            assert_eq!(v.dot(v.rotate_90_ccw()), 0);
+            assert_eq!(Point::unit_x().dot(Point::unit_y()), 0);
+            assert_eq!(v.dot(v), v.squared_distance_to(Point::ORIGIN));
+        }
+
+        #[test]
+        fn cross_gives_orientation() {
+            let a = Point { x: 2, y: 1 };
+            let b = Point { x: -1, y: 3 };
+
+            assert_eq!(a.cross(b), 7);
+            assert_eq!(b.cross(a), -7);
+            assert_eq!(a.cross(a * 5), 0);
+        }
+
+        #[test]
+        fn widen_large_coordinates() {
+            let max = Point {
+                x: i32::MAX,
+                y: i32::MAX,
+            };
+            let min = Point {
+                x: i32::MIN,
+                y: i32::MIN,
+            };
+            let skew = Point {
+                x: i32::MIN,
+                y: i32::MAX,
+            };
+
+            assert_eq!(max.dot(max), 2 * i64::from(i32::MAX).pow(2));
+            assert_eq!(skew.dot(min), 1 << 31);
+            assert_eq!(skew.cross(min), i64::MAX - i64::from(i32::MAX));
+            assert_eq!(min.cross(skew), -(i64::MAX - i64::from(i32::MAX)));
+        }
+    }
+
+    mod with {
+        use super::*;
+
+        #[test]
+        fn replaces_single_coordinate() {
+            let p = Point { x: 1, y: 2 };
+
+            assert_eq!(p.with_x(-7), Point { x: -7, y: 2 });
+            assert_eq!(p.with_y(9), Point { x: 1, y: 9 });
+            assert_eq!(p, Point { x: 1, y: 2 });
+        }
+
+        #[test]
+        fn chains_from_const() {
+            const P: Point = Point::ORIGIN.with_x(5).with_y(3);
+
+            assert_eq!(P, Point { x: 5, y: 3 });
+            assert_eq!(Point::ORIGIN, Point { x: 0, y: 0 });
+        }
+    }
+
+    mod neighbors {
+        use super::*;
+
+        #[test]
+        fn lists_orthogonal_neighbors_of_origin() {
+            assert_eq!(
+                Point::ORIGIN.neighbors4(),
+                [
+                    Point { x: 0, y: 1 },
+                    Point { x: 0, y: -1 },
+                    Point { x: -1, y: 0 },
+                    Point { x: 1, y: 0 },
+                ],
+            );
+        }
+
+        #[test]
+        fn lists_all_neighbors_of_origin() {
+            let mut neighbors = Point::ORIGIN.neighbors8();
+            neighbors.sort_unstable();
+
+            assert_eq!(
+                neighbors,
+                [
+                    (-1, -1),
+                    (-1, 0),
+                    (-1, 1),
+                    (0, -1),
+                    (0, 1),
+                    (1, -1),
+                    (1, 0),
+                    (1, 1)
+                ]
+                .map(Point::from),
+            );
+        }
+
+        #[test]
+        fn includes_orthogonal_in_all_neighbors() {
+            let p = Point { x: 7, y: -3 };
+
+            assert_eq!(p.neighbors8()[..4], p.neighbors4());
+            for n in p.neighbors8() {
+                assert_eq!((n.x - p.x).abs().max((n.y - p.y).abs()), 1);
+            }
+        }
+    }
+
+    mod spatial_bucket {
+        use super::*;
+
+        #[test]
+        fn groups_nearby_points() {
+            assert_eq!(Point { x: 0, y: 0 }.spatial_bucket(10), (0, 0));
+            assert_eq!(Point { x: 9, y: 9 }.spatial_bucket(10), (0, 0));
+            assert_eq!(
+                Point { x: 21, y: -5 }.spatial_bucket(10),
+                Point { x: 28, y: -1 }.spatial_bucket(10),
+            );
+        }
+
+        #[test]
+        fn separates_distant_points() {
+            assert_ne!(
+                Point { x: 0, y: 0 }.spatial_bucket(10),
+                Point { x: 10, y: 0 }.spatial_bucket(10),
+            );
+            assert_ne!(
+                Point { x: 5, y: 5 }.spatial_bucket(10),
+                Point { x: 105, y: -95 }.spatial_bucket(10),
+            );
+        }
+
+        #[test]
+        fn floors_negative_coordinates() {
+            assert_eq!(Point { x: -1, y: -10 }.spatial_bucket(10), (-1, -1));
+            assert_eq!(Point { x: -11, y: 10 }.spatial_bucket(10), (-2, 1));
+            assert_eq!(
+                Point {
+                    x: i32::MIN,
+                    y: i32::MAX
+                }
+                .spatial_bucket(1),
+                (i32::MIN, i32::MAX),
+            );
+        }
+    }
+
+    mod i16 {
+        use super::*;
+
+        #[test]
+        fn round_trips_in_range_coordinates() {
+            for (x, y) in [(0, 0), (3, -4), (i16::MIN, i16::MAX)] {
+                let p = Point::from_i16(x, y);
+
+                assert_eq!(
+                    p,
+                    Point {
+                        x: x.into(),
+                        y: y.into()
+                    }
+                );
+                assert_eq!(p.to_i16(), Some((x, y)));
+            }
+        }
+
+        #[test]
+        fn returns_none_for_out_of_range_coordinates() {
+            assert_eq!(Point { x: 32_768, y: 0 }.to_i16(), None);
+            assert_eq!(Point { x: 0, y: -32_769 }.to_i16(), None);
+            assert_eq!(
+                Point {
+                    x: i32::MAX,
+                    y: i32::MIN
+                }
+                .to_i16(),
+                None
+            );
+        }
+    }
+
+    mod abs {
+        use super::*;
+
+        #[test]
+        fn negates_negative_coordinates() {
+            assert_eq!(Point { x: -3, y: 4 }.abs(), Point { x: 3, y: 4 });
+            assert_eq!(Point { x: 0, y: -7 }.abs(), Point { x: 0, y: 7 });
+        }
+
+        #[test]
+        fn saturates_at_min() {
+            assert_eq!(
+                Point {
+                    x: i32::MIN,
+                    y: i32::MAX
+                }
+                .abs(),
+                Point {
+                    x: i32::MAX,
+                    y: i32::MAX
+                },
+            );
+            assert_eq!(
+                Point {
+                    x: i32::MIN + 1,
+                    y: i32::MIN
+                }
+                .abs(),
+                Point {
+                    x: i32::MAX,
+                    y: i32::MAX
+                },
+            );
+        }
+    }
+
+    mod signum {
+        use super::*;
+
+        #[test]
+        fn returns_per_axis_sign() {
+            assert_eq!(Point { x: -3, y: 4 }.signum(), Point { x: -1, y: 1 });
+            assert_eq!(Point { x: 0, y: -7 }.signum(), Point { x: 0, y: -1 });
+            assert_eq!(Point::ORIGIN.signum(), Point::ORIGIN);
+            assert_eq!(
+                Point {
+                    x: i32::MIN,
+                    y: i32::MAX
+                }
+                .signum(),
+                Point { x: -1, y: 1 },
+            );
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use alloc::string::String;
+use core::{error::Error, fmt};
+
+/// Error of parsing a [`Point`] (or a [`Polyline`] of them) from any of its
+/// textual forms.
+///
+/// [`Point`]: super::Point
+/// [`Polyline`]: crate::Polyline
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PointParseError {
+    /// Input has nothing to parse at all.
+    Empty,
+
+    /// Coordinate is missing, like the `y` of a `"3"` or `"3,"` input.
+    MissingComponent,
+
+    /// Provided token is not an integer number.
+    NotANumber(String),
+
+    /// Coordinate doesn't fit into the [`i32`] range.
+    OutOfRange,
+}
+
+impl fmt::Display for PointParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("expected a point, but input is empty"),
+            Self::MissingComponent => f.write_str("expected a point, but a coordinate is missing"),
+            Self::NotANumber(token) => write!(f, "`{token}` is not an integer number"),
+            Self::OutOfRange => f.write_str("coordinate is out of `i32` range"),
+        }
+    }
+}
+
+impl Error for PointParseError {}
+
+#[cfg(test)]
+mod spec {
+    use alloc::{boxed::Box, string::ToString as _};
+
+    use super::*;
+
+    mod display {
+        use super::*;
+
+        #[test]
+        fn describes_each_variant() {
+            assert_eq!(
+                PointParseError::Empty.to_string(),
+                "expected a point, but input is empty",
+            );
+            assert_eq!(
+                PointParseError::MissingComponent.to_string(),
+                "expected a point, but a coordinate is missing",
+            );
+            assert_eq!(
+                PointParseError::NotANumber("four".into()).to_string(),
+                "`four` is not an integer number",
+            );
+            assert_eq!(
+                PointParseError::OutOfRange.to_string(),
+                "coordinate is out of `i32` range",
+            );
+        }
+
+        #[test]
+        fn is_core_error() {
+            let err: Box<dyn Error> = Box::new(PointParseError::MissingComponent);
+
+            assert!(err.source().is_none());
+            assert_eq!(
+                err.to_string(),
+                "expected a point, but a coordinate is missing",
+            );
+        }
+    }
+}
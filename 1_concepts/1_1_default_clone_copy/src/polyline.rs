@@ -0,0 +1,2713 @@
+mod builder;
+mod circle;
+mod clip;
+mod coords;
+mod error;
+mod geojson;
+mod grid;
+mod hull;
+mod intersect;
+mod metric;
+mod offset;
+mod simplify;
+mod slice;
+mod spline;
+mod winding;
+
+use std::{
+    f64::consts::{PI, TAU},
+    fmt,
+    hash::{Hash, Hasher},
+    iter, mem,
+    sync::OnceLock,
+    vec,
+};
+
+use crate::{Affine2, Point};
+
+pub use self::{
+    builder::PolylineBuilder,
+    error::PolylineError,
+    intersect::segment_intersection,
+    metric::{Chebyshev, Euclidean, Manhattan, Metric},
+    slice::PolylineSlice,
+};
+
+/// Non-empty set of [`Point`]s of unknown size.
+///
+/// The `start` vertex is stored apart from the rest, so the "at least one
+/// point" invariant is guaranteed structurally rather than just documented.
+#[derive(Clone)]
+pub struct Polyline {
+    start: Point,
+    points: Vec<Point>,
+
+    /// Memoized [`Polyline::total_length()`], reset by every mutation.
+    ///
+    /// Being derived from the vertices, it's ignored by [`PartialEq`] and
+    /// [`Hash`].
+    length: OnceLock<f64>,
+}
+
+impl Polyline {
+    /// Creates a new [`Polyline`] consisting of the single `start` vertex.
+    #[must_use]
+    pub const fn new(start: Point) -> Self {
+        Self {
+            start,
+            points: Vec::new(),
+            length: OnceLock::new(),
+        }
+    }
+
+    /// Creates a new [`Polyline`] from its `start` vertex followed by the
+    /// `rest` of them.
+    #[must_use]
+    pub const fn with_points(start: Point, rest: Vec<Point>) -> Self {
+        Self {
+            start,
+            points: rest,
+            length: OnceLock::new(),
+        }
+    }
+
+    /// First vertex of this [`Polyline`].
+    #[must_use]
+    pub const fn start(&self) -> Point {
+        self.start
+    }
+
+    /// Appends the `p` vertex to the end of this [`Polyline`].
+    pub fn push(&mut self, p: Point) {
+        self.invalidate();
+        self.points.push(p);
+    }
+
+    /// Inserts the `p` vertex at the provided `index` (in the `start`-first
+    /// order), shifting all the vertices after it, so inserting at `0` makes
+    /// it the new `start`.
+    ///
+    /// # Panics
+    ///
+    /// If the `index` is greater than [`Polyline::len()`].
+    pub fn insert(&mut self, index: usize, p: Point) {
+        assert!(
+            index <= self.len(),
+            "insertion index (is {index}) should be <= len (is {})",
+            self.len(),
+        );
+        self.invalidate();
+        match index {
+            0 => self.points.insert(0, mem::replace(&mut self.start, p)),
+            i => self.points.insert(i - 1, p),
+        }
+    }
+
+    /// Removes and returns the vertex at the provided `index` (in the
+    /// `start`-first order), so removing at `0` promotes the next vertex to
+    /// the `start`.
+    ///
+    /// Returns [`None`] if the `index` is out of range, or if the vertex is the
+    /// only one remaining, as a [`Polyline`] cannot be empty.
+    pub fn remove(&mut self, index: usize) -> Option<Point> {
+        if self.points.is_empty() || index >= self.len() {
+            return None;
+        }
+        self.invalidate();
+        Some(match index {
+            0 => mem::replace(&mut self.start, self.points.remove(0)),
+            i => self.points.remove(i - 1),
+        })
+    }
+
+    /// Appends all the vertices of the `other` [`Polyline`] (including its
+    /// `start`) after the last vertex of this one.
+    pub fn append(&mut self, other: &Self) {
+        self.invalidate();
+        self.points.extend(other.iter());
+    }
+
+    /// Checks whether this [`Polyline`] is a closed loop, ending at its
+    /// `start` (which a single-vertex one trivially does).
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        self.points.last().unwrap_or(&self.start) == &self.start
+    }
+
+    /// Closes this [`Polyline`] into a loop, appending its `start` as the last
+    /// vertex, unless it [`is_closed()`] already.
+    ///
+    /// [`is_closed()`]: Polyline::is_closed
+    #[must_use]
+    pub fn closed(mut self) -> Self {
+        if !self.is_closed() {
+            self.push(self.start);
+        }
+        self
+    }
+
+    /// Splits this [`Polyline`] into two at the vertex with the provided
+    /// `index` (in the `start`-first order), which becomes both the last
+    /// vertex of the first piece and the `start` of the second one.
+    ///
+    /// Splitting at either endpoint produces a single-vertex piece, so both
+    /// pieces are never empty. An out-of-range `index` is clamped to the last
+    /// vertex.
+    #[must_use]
+    pub fn split_at(&self, index: usize) -> (Self, Self) {
+        let vertices: Vec<_> = self.iter().copied().collect();
+        let index = index.min(vertices.len() - 1);
+        (
+            vertices[..=index].iter().copied().collect(),
+            vertices[index..].iter().copied().collect(),
+        )
+    }
+
+    /// Splits this [`Polyline`] into pieces at every interior vertex where it
+    /// turns (in either direction) sharper than the `threshold_rad`, as
+    /// measured by [`Polyline::turn_angles()`].
+    ///
+    /// Each corner vertex becomes both the last vertex of one piece and the
+    /// `start` of the next one, so all the pieces are non-empty, and a single
+    /// piece equal to this [`Polyline`] is returned if there are no sharp
+    /// turns.
+    #[must_use]
+    pub fn split_by_angle(&self, threshold_rad: f64) -> Vec<Self> {
+        let vertices: Vec<_> = self.iter().copied().collect();
+        let mut pieces = vec![];
+        let mut from = 0;
+        for (i, angle) in self.turn_angles().into_iter().enumerate() {
+            if angle.abs() > threshold_rad {
+                let corner = i + 1;
+                pieces.push(vertices[from..=corner].iter().copied().collect());
+                from = corner;
+            }
+        }
+        pieces.push(vertices[from..].iter().copied().collect());
+        pieces
+    }
+
+    /// Removes the vertices equal to their predecessors, so the `start` is
+    /// always kept, even if all the vertices are the same.
+    pub fn dedup_consecutive(&mut self) {
+        self.invalidate();
+        let mut prev = self.start;
+        self.points.retain(|&p| mem::replace(&mut prev, p) != p);
+    }
+
+    /// By-value version of [`Polyline::append()`], reusing the vertices of
+    /// the `other` [`Polyline`].
+    #[must_use]
+    pub fn concat(mut self, other: Self) -> Self {
+        self.invalidate();
+        self.points.extend(other);
+        self
+    }
+
+    /// Number of vertices in this [`Polyline`], which is never zero.
+    #[expect(clippy::len_without_is_empty, reason = "`Polyline` is never empty")]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        1 + self.points.len()
+    }
+
+    /// Iterates over all the vertices of this [`Polyline`], `start` first.
+    pub fn iter(&self) -> impl Iterator<Item = &Point> {
+        iter::once(&self.start).chain(&self.points)
+    }
+
+    /// Folds all the vertices of this [`Polyline`] (`start` first) into an
+    /// accumulator, starting from the `init` one.
+    pub fn fold_vertices<B, F: FnMut(B, Point) -> B>(&self, init: B, f: F) -> B {
+        self.iter().copied().fold(init, f)
+    }
+
+    /// Returns an iterator over the segments of this [`Polyline`], as pairs of
+    /// its consecutive vertices.
+    ///
+    /// Yields `len() - 1` segments, so none for a single-vertex [`Polyline`].
+    pub fn segments(&self) -> impl Iterator<Item = (Point, Point)> {
+        self.iter().copied().zip(self.iter().copied().skip(1))
+    }
+
+    /// Cumulative length of all the segments of this [`Polyline`].
+    ///
+    /// It's `0.0` for a single-vertex [`Polyline`]. The length is computed
+    /// once and memoized until this [`Polyline`] is mutated.
+    #[must_use]
+    pub fn total_length(&self) -> f64 {
+        *self
+            .length
+            .get_or_init(|| self.segments().map(|(a, b)| a.distance_to(b)).sum())
+    }
+
+    /// Sum of the squared lengths of all the segments of this [`Polyline`],
+    /// computed exactly in [`i64`] without floating point.
+    ///
+    /// For the same [`total_length()`], it grows with the unevenness of the
+    /// segments, so it can compare the "roughness" of paths. It can only
+    /// overflow for segments spanning most of the [`i32`] range.
+    ///
+    /// [`total_length()`]: Polyline::total_length
+    #[must_use]
+    pub fn total_squared_segment_lengths(&self) -> i64 {
+        self.segments().map(|(a, b)| a.squared_distance_to(b)).sum()
+    }
+
+    /// Axis-aligned bounding box of this [`Polyline`], returned as its
+    /// `(min, max)` corners.
+    ///
+    /// Both corners are the same [`Point`] for a single-vertex [`Polyline`].
+    #[must_use]
+    pub fn bounding_box(&self) -> (Point, Point) {
+        bounds(self.start, &self.points)
+    }
+
+    /// Checks whether the `x` coordinate never decreases along this
+    /// [`Polyline`], so it can be treated as a graph of a function of `x`
+    /// (with vertical segments allowed).
+    #[must_use]
+    pub fn is_monotonic_x(&self) -> bool {
+        self.segments().all(|(a, b)| a.x <= b.x)
+    }
+
+    /// Checks whether the `y` coordinate never decreases along this
+    /// [`Polyline`], so it can be treated as a graph of a function of `y`
+    /// (with horizontal segments allowed).
+    #[must_use]
+    pub fn is_monotonic_y(&self) -> bool {
+        self.segments().all(|(a, b)| a.y <= b.y)
+    }
+
+    /// Evaluates this [`Polyline`] as a piecewise-linear function of `x`,
+    /// interpolating the `y` coordinate at the provided `x` linearly within
+    /// the containing segment.
+    ///
+    /// At the `x` of a vertical segment, the `y` of the first vertex reached
+    /// is returned.
+    ///
+    /// Returns [`None`] if this [`Polyline`] is not [`is_monotonic_x()`], or
+    /// the `x` lies outside of its domain.
+    ///
+    /// [`is_monotonic_x()`]: Polyline::is_monotonic_x
+    #[must_use]
+    pub fn value_at_x(&self, x: i32) -> Option<f64> {
+        if !self.is_monotonic_x() {
+            return None;
+        }
+        if x == self.start.x {
+            return Some(f64::from(self.start.y));
+        }
+        self.segments()
+            .find(|(a, b)| (a.x..=b.x).contains(&x))
+            .map(|(a, b)| {
+                let t = (f64::from(x) - f64::from(a.x)) / (f64::from(b.x) - f64::from(a.x));
+                f64::from(a.y) + (f64::from(b.y) - f64::from(a.y)) * t
+            })
+    }
+
+    /// Computes the signed area enclosed by this [`Polyline`] treated as a
+    /// closed loop (its last vertex implicitly connected back to the `start`),
+    /// with the [shoelace formula][1].
+    ///
+    /// The area is positive for counter-clockwise vertices and negative for
+    /// clockwise ones. The sum is accumulated in [`i128`], so it's exact for
+    /// any coordinates before the final halving.
+    ///
+    /// [1]: https://en.wikipedia.org/wiki/Shoelace_formula
+    #[must_use]
+    pub fn signed_area(&self) -> f64 {
+        let last = *self.points.last().unwrap_or(&self.start);
+        let doubled: i128 = self
+            .segments()
+            .chain(iter::once((last, self.start)))
+            .map(|(a, b)| i128::from(a.x) * i128::from(b.y) - i128::from(b.x) * i128::from(a.y))
+            .sum();
+        doubled as f64 / 2.0
+    }
+
+    /// Checks whether the `other` [`Polyline`] traces the same path as this
+    /// one, either in the same or in the opposite direction.
+    ///
+    /// Unlike [`PartialEq`], this ignores the direction of the path.
+    #[must_use]
+    pub fn same_path_as(&self, other: &Self) -> bool {
+        self == other || (self.len() == other.len() && *self == other.reversed())
+    }
+
+    /// Checks whether the `other` [`Polyline`] has the same number of vertices
+    /// as this one, with each of their corresponding coordinates differing by
+    /// at most the `tol`.
+    ///
+    /// Unlike [`PartialEq`], this tolerates rounding differences of results
+    /// produced by floating point transforms (like [`Polyline::resample()`]).
+    /// Differences are computed in [`i64`], so they never overflow, while
+    /// a negative `tol` is never satisfied.
+    #[must_use]
+    pub fn approx_eq(&self, other: &Self, tol: i32) -> bool {
+        let close = |a: i32, b: i32| (i64::from(a) - i64::from(b)).abs() <= i64::from(tol);
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| close(a.x, b.x) && close(a.y, b.y))
+    }
+
+    /// Returns the signed turning angle (in radians) at each interior vertex of
+    /// this [`Polyline`], being the change of heading between its adjacent
+    /// segments (see [`Point::angle_to()`]).
+    ///
+    /// Angles lie in the `(-π, π]` range, positive for left turns and negative
+    /// for right ones. There are `len() - 2` of them, so none for a
+    /// [`Polyline`] of fewer than 3 vertices.
+    #[must_use]
+    pub fn turn_angles(&self) -> Vec<f64> {
+        self.segments()
+            .zip(self.segments().skip(1))
+            .map(|((a, b), (_, c))| {
+                let turn = b.angle_to(c) - a.angle_to(b);
+                if turn > PI {
+                    turn - TAU
+                } else if turn <= -PI {
+                    turn + TAU
+                } else {
+                    turn
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the arithmetic mean of all the vertices of this [`Polyline`].
+    ///
+    /// Coordinates are summed in [`i64`], so this never overflows, and the
+    /// mean is rounded to the nearest integer, with halves rounded away from
+    /// zero (same as [`Point::lerp`] does).
+    #[must_use]
+    pub fn centroid(&self) -> Point {
+        mean(self.iter())
+    }
+
+    /// Smooths this [`Polyline`] with a moving average, replacing each of its
+    /// interior vertices with the mean of the ones within the `window`
+    /// centered on it (`window / 2` neighbors on each side, fewer near the
+    /// ends).
+    ///
+    /// The first and the last vertices stay fixed as anchors, while the means
+    /// are rounded like in [`Polyline::centroid()`]. A `window` of `0` or `1`
+    /// leaves this [`Polyline`] unchanged.
+    #[must_use]
+    pub fn smooth(&self, window: usize) -> Self {
+        let vertices: Vec<_> = self.iter().copied().collect();
+        let (radius, last) = (window / 2, vertices.len() - 1);
+        (0..=last)
+            .map(|i| {
+                if radius == 0 || i == 0 || i == last {
+                    vertices[i]
+                } else {
+                    mean(&vertices[i.saturating_sub(radius)..=(i + radius).min(last)])
+                }
+            })
+            .collect()
+    }
+
+    /// Smooths this [`Polyline`] with the provided number of `iterations` of
+    /// Chaikin's corner cutting, replacing each segment with the points
+    /// at its `1/4` and `3/4`, interpolated with [`Point::lerp()`].
+    ///
+    /// The first and the last vertices stay fixed as anchors, so every
+    /// iteration doubles the `len()` of a [`Polyline`] with at least 2
+    /// vertices, while a single-vertex one stays unchanged.
+    #[must_use]
+    pub fn chaikin(&self, iterations: usize) -> Self {
+        let mut smoothed = self.clone();
+        for _ in 0..iterations {
+            if smoothed.points.is_empty() {
+                break;
+            }
+            let last = *smoothed.points.last().unwrap_or(&smoothed.start);
+            let mut cut = Self::new(smoothed.start);
+            for (a, b) in smoothed.segments() {
+                cut.push(a.lerp(b, 0.25));
+                cut.push(a.lerp(b, 0.75));
+            }
+            cut.push(last);
+            smoothed = cut;
+        }
+        smoothed
+    }
+
+    /// Reverses the order of vertices of this [`Polyline`] in place, so the
+    /// last vertex becomes its `start`.
+    pub fn reverse(&mut self) {
+        if self.points.is_empty() {
+            return;
+        }
+        self.invalidate();
+        // [p1, .., pn] -> [pn, .., p1] -> [p0, pn-1, .., p1] -> [pn-1, .., p1, p0]
+        self.points.reverse();
+        mem::swap(&mut self.start, &mut self.points[0]);
+        self.points.rotate_left(1);
+    }
+
+    /// Cyclically shifts the vertices of this [`Polyline`] `by` the provided
+    /// number of positions, so the vertex at the `by` index (in the
+    /// `start`-first order, modulo the vertices count) becomes its `start`.
+    ///
+    /// A closed [`Polyline`] stays closed, being rotated along its loop of
+    /// distinct vertices (without the duplicated `start` at its end), while
+    /// an open one is rotated as a whole, so its last vertex gets followed by
+    /// the former `start`.
+    pub fn rotate_start(&mut self, by: usize) {
+        let closed = self.is_closed() && !self.points.is_empty();
+        let mut vertices: Vec<_> = self.iter().copied().collect();
+        if closed {
+            vertices.pop();
+        }
+        let by = by % vertices.len();
+        if by == 0 {
+            return;
+        }
+        vertices.rotate_left(by);
+        if closed {
+            vertices.push(vertices[0]);
+        }
+        *self = vertices.into_iter().collect();
+    }
+
+    /// Rotates this [`Polyline`] with [`Polyline::rotate_start()`], so its
+    /// lexicographically smallest vertex (the first one, if repeated) becomes
+    /// the `start`.
+    ///
+    /// This makes closed [`Polyline`]s tracing the same loop in the same
+    /// direction compare equal regardless of the vertex they started at.
+    pub fn canonical_start(&mut self) {
+        let cycle = if self.is_closed() {
+            self.len() - 1
+        } else {
+            self.len()
+        };
+        let smallest = self
+            .iter()
+            .take(cycle.max(1))
+            .enumerate()
+            .min_by_key(|(_, p)| **p)
+            .map_or(0, |(i, _)| i);
+        self.rotate_start(smallest);
+    }
+
+    /// Returns a copy of this [`Polyline`] with the reversed order of vertices.
+    #[must_use]
+    pub fn reversed(&self) -> Self {
+        let mut reversed = self.clone();
+        reversed.reverse();
+        reversed
+    }
+
+    /// Moves every vertex of this [`Polyline`] by the provided `offset`.
+    pub fn translate(&mut self, offset: Point) {
+        for p in self.iter_mut() {
+            *p += offset;
+        }
+    }
+
+    /// Multiplies every coordinate of this [`Polyline`] by the provided
+    /// `factor`.
+    pub fn scale(&mut self, factor: i32) {
+        for p in self.iter_mut() {
+            *p = *p * factor;
+        }
+    }
+
+    /// Returns a new [`Polyline`] made of this one's vertices transformed by
+    /// the provided `f`, applied `start` first.
+    #[must_use]
+    pub fn map<F: FnMut(Point) -> Point>(&self, mut f: F) -> Self {
+        Self {
+            start: f(self.start),
+            points: self.points.iter().map(|&p| f(p)).collect(),
+            length: OnceLock::new(),
+        }
+    }
+
+    /// Returns a new [`Polyline`] with every vertex of this one transformed by
+    /// the provided [`Affine2`].
+    #[must_use]
+    pub fn transformed(&self, transform: &Affine2) -> Self {
+        self.map(|p| transform.transform_point(p))
+    }
+
+    /// Resets the memoized [`Polyline::total_length()`], so it's recomputed
+    /// on the next call.
+    fn invalidate(&mut self) {
+        self.length.take();
+    }
+
+    /// Iterates mutably over all the vertices of this [`Polyline`], `start`
+    /// first (invalidating the memoized length beforehand).
+    fn iter_mut(&mut self) -> impl Iterator<Item = &mut Point> {
+        self.invalidate();
+        iter::once(&mut self.start).chain(&mut self.points)
+    }
+
+    /// Vertex of this [`Polyline`] closest to the provided `query` [`Point`].
+    ///
+    /// Distances are compared exactly, and the first vertex in the iteration
+    /// order wins on ties.
+    #[must_use]
+    pub fn nearest_vertex(&self, query: Point) -> Point {
+        *self
+            .iter()
+            .min_by_key(|p| p.squared_distance_to(query))
+            .unwrap_or(&self.start)
+    }
+
+    /// Projects the provided `query` [`Point`] onto this [`Polyline`],
+    /// returning the closest point lying anywhere on its segments (not only at
+    /// its vertices) along with the arc length from the `start` at which it
+    /// occurs.
+    ///
+    /// The closest point is rounded with [`Point::lerp()`], while distances
+    /// are compared before rounding, and the first segment in the iteration
+    /// order wins on ties.
+    #[must_use]
+    pub fn project(&self, query: Point) -> (Point, f64) {
+        let mut closest = (self.start, 0.0);
+        let mut closest_distance = self.start.distance_to(query);
+        let mut covered = 0.0;
+        for (a, b) in self.segments() {
+            let (abx, aby) = (
+                f64::from(b.x) - f64::from(a.x),
+                f64::from(b.y) - f64::from(a.y),
+            );
+            let (aqx, aqy) = (
+                f64::from(query.x) - f64::from(a.x),
+                f64::from(query.y) - f64::from(a.y),
+            );
+            let t = if a == b {
+                0.0
+            } else {
+                ((aqx * abx + aqy * aby) / (abx * abx + aby * aby)).clamp(0.0, 1.0)
+            };
+            let distance = (aqx - t * abx).hypot(aqy - t * aby);
+            let len = a.distance_to(b);
+            if distance < closest_distance {
+                closest = (a.lerp(b, t), covered + t * len);
+                closest_distance = distance;
+            }
+            covered += len;
+        }
+        closest
+    }
+
+    /// Subdivides this [`Polyline`], inserting the [`Point::midpoint()`] of
+    /// each of its segments between the segment endpoints.
+    ///
+    /// All the original vertices are preserved, and the resulting
+    /// [`Polyline`] has `2 * len() - 1` vertices.
+    #[must_use]
+    pub fn subdivide(&self) -> Self {
+        let mut subdivided = Self::new(self.start);
+        for (a, b) in self.segments() {
+            subdivided.push(a.midpoint(b));
+            subdivided.push(b);
+        }
+        subdivided
+    }
+
+    /// Densifies this [`Polyline`], splitting each of its segments longer than
+    /// the `max_len` into the minimal number of equal parts not exceeding it.
+    ///
+    /// All the original vertices are preserved, while the inserted ones are
+    /// rounded to the nearest integer coordinates with [`Point::lerp`], so the
+    /// resulting segments may exceed the `max_len` by the rounding error.
+    ///
+    /// # Panics
+    ///
+    /// If the `max_len` is not positive.
+    #[must_use]
+    pub fn densify(&self, max_len: f64) -> Self {
+        assert!(max_len > 0.0, "`max_len` must be positive");
+
+        let mut densified = Self::new(self.start);
+        for (a, b) in self.segments() {
+            let parts = (a.distance_to(b) / max_len).ceil().max(1.0) as u32;
+            for k in 1..parts {
+                densified.push(a.lerp(b, f64::from(k) / f64::from(parts)));
+            }
+            densified.push(b);
+        }
+        densified
+    }
+
+    /// Returns the point located at the `arc_length` distance along this
+    /// [`Polyline`] from its `start`.
+    ///
+    /// The point is interpolated within the containing segment with
+    /// [`Point::lerp`], while the `arc_length` outside of the
+    /// `0.0..=total_length()` range is clamped to the respective endpoint.
+    #[must_use]
+    pub fn point_at(&self, arc_length: f64) -> Point {
+        let mut covered = 0.0;
+        for (a, b) in self.segments() {
+            let len = a.distance_to(b);
+            if covered + len >= arc_length {
+                return if len == 0.0 {
+                    a
+                } else {
+                    a.lerp(b, (arc_length - covered) / len)
+                };
+            }
+            covered += len;
+        }
+        *self.points.last().unwrap_or(&self.start)
+    }
+
+    /// Returns the prefix sums of the segment lengths of this [`Polyline`]: the
+    /// arc length from its `start` to each of its `len()` vertices (so the
+    /// first one is always `0.0`, and the last one is the `total_length()`).
+    #[must_use]
+    pub fn arc_length_table(&self) -> Vec<f64> {
+        self.iter_with_arclen()
+            .map(|(_, covered)| covered)
+            .collect()
+    }
+
+    /// Iterates over all the vertices of this [`Polyline`], `start` first,
+    /// paired with their arc length from the `start`, as in the
+    /// [`Polyline::arc_length_table()`], but computed lazily.
+    ///
+    /// The first arc length is always `0.0`, and the last one is the
+    /// [`Polyline::total_length()`].
+    pub fn iter_with_arclen(&self) -> impl Iterator<Item = (Point, f64)> {
+        let (mut prev, mut covered) = (self.start, 0.0);
+        self.iter().map(move |&p| {
+            covered += prev.distance_to(p);
+            prev = p;
+            (p, covered)
+        })
+    }
+
+    /// Same as [`Polyline::point_at()`], but finds the containing segment in
+    /// `O(log n)` with the provided `table` precomputed by
+    /// [`Polyline::arc_length_table()`], which is useful when sampling many
+    /// points.
+    ///
+    /// The `table` is expected to be computed for this very [`Polyline`],
+    /// which is checked in debug builds only.
+    #[must_use]
+    pub fn point_at_with(&self, arc_length: f64, table: &[f64]) -> Point {
+        debug_assert_eq!(
+            table.len(),
+            self.len(),
+            "`table` doesn't match this `Polyline`",
+        );
+
+        let i = table[1..].partition_point(|&covered| covered < arc_length);
+        if i + 1 >= table.len() {
+            return *self.points.last().unwrap_or(&self.start);
+        }
+        let (a, b) = (self.vertex(i), self.vertex(i + 1));
+        let len = a.distance_to(b);
+        if len == 0.0 {
+            a
+        } else {
+            a.lerp(b, (arc_length - table[i]) / len)
+        }
+    }
+
+    /// Returns the vertex of this [`Polyline`] with the provided `index` (in
+    /// the `start`-first order).
+    fn vertex(&self, index: usize) -> Point {
+        match index {
+            0 => self.start,
+            i => self.points[i - 1],
+        }
+    }
+
+    /// Resamples this [`Polyline`] into the `count` vertices distributed at
+    /// equal arc-length intervals along it.
+    ///
+    /// Both endpoints are preserved (a single `start` remains for the `count`
+    /// of `1`), while the intermediate vertices are sampled with
+    /// [`Polyline::point_at()`].
+    ///
+    /// # Panics
+    ///
+    /// If the `count` is zero.
+    #[must_use]
+    pub fn resample(&self, count: usize) -> Self {
+        self.try_resample(count)
+            .expect("cannot resample Polyline into zero vertices")
+    }
+
+    /// Fallible version of [`Polyline::resample()`].
+    ///
+    /// # Errors
+    ///
+    /// [`PolylineError::InvalidCount`] if the `count` is zero.
+    pub fn try_resample(&self, count: usize) -> Result<Self, PolylineError> {
+        if count == 0 {
+            return Err(PolylineError::InvalidCount);
+        }
+
+        let last = *self.points.last().unwrap_or(&self.start);
+        let table = self.arc_length_table();
+        let step = table[table.len() - 1] / (count - 1).max(1) as f64;
+
+        let mut resampled = Self::new(self.start);
+        for i in 1..count {
+            resampled.push(if i == count - 1 {
+                last
+            } else {
+                self.point_at_with(step * i as f64, &table)
+            });
+        }
+        Ok(resampled)
+    }
+
+    /// Exports this [`Polyline`] as a canonical JSON object, like
+    /// `{"start":{"x":0,"y":0},"points":[{"x":1,"y":2}]}`, without pulling any
+    /// dependencies.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let points: Vec<_> = self.points.iter().map(|p| p.to_json()).collect();
+        format!(
+            r#"{{"start":{},"points":[{}]}}"#,
+            self.start.to_json(),
+            points.join(","),
+        )
+    }
+
+    /// Exports this [`Polyline`] as an open SVG path data (a `d` attribute),
+    /// like `M 0 0 L 1 2 L -3 4`, `start` vertex first.
+    #[must_use]
+    pub fn to_svg_path(&self) -> String {
+        let mut path = format!("M {} {}", self.start.x, self.start.y);
+        for p in &self.points {
+            path.push_str(&format!(" L {} {}", p.x, p.y));
+        }
+        path
+    }
+
+    /// Exports this [`Polyline`] as a closed SVG path data (a `d` attribute),
+    /// like `M 0 0 L 1 2 L -3 4 Z`, connecting its last vertex back to the
+    /// `start` one.
+    #[must_use]
+    pub fn to_closed_svg_path(&self) -> String {
+        self.to_svg_path() + " Z"
+    }
+}
+
+impl PartialEq for Polyline {
+    fn eq(&self, other: &Self) -> bool {
+        self.start == other.start && self.points == other.points
+    }
+}
+
+impl Eq for Polyline {}
+
+impl Hash for Polyline {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.start.hash(state);
+        self.points.hash(state);
+    }
+}
+
+/// Renders this [`Polyline`] as `Polyline[(x0,y0) -> (x1,y1) -> ...]`.
+impl fmt::Debug for Polyline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Polyline[")?;
+        for (i, p) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" -> ")?;
+            }
+            write!(f, "({},{})", p.x, p.y)?;
+        }
+        f.write_str("]")
+    }
+}
+
+/// Renders this [`Polyline`] as `(x0, y0) -> (x1, y1) -> ...`.
+impl fmt::Display for Polyline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, p) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" -> ")?;
+            }
+            write!(f, "{p}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromIterator<Point> for Polyline {
+    /// Collects the yielded vertices into a [`Polyline`], the first of them
+    /// becoming its `start`.
+    ///
+    /// # Panics
+    ///
+    /// If the provided iterator yields no [`Point`]s at all.
+    fn from_iter<I: IntoIterator<Item = Point>>(iter: I) -> Self {
+        let mut iter = iter.into_iter();
+        let start = iter
+            .next()
+            .expect("cannot build Polyline from empty iterator");
+        Self::with_points(start, iter.collect())
+    }
+}
+
+impl TryFrom<Vec<Point>> for Polyline {
+    type Error = PolylineError;
+
+    /// Converts the provided `vertices` into a [`Polyline`], the first of them
+    /// becoming its `start`.
+    ///
+    /// # Errors
+    ///
+    /// [`PolylineError::Empty`] if there are no `vertices` at all.
+    fn try_from(mut vertices: Vec<Point>) -> Result<Self, Self::Error> {
+        if vertices.is_empty() {
+            return Err(PolylineError::Empty);
+        }
+        let start = vertices.remove(0);
+        Ok(Self::with_points(start, vertices))
+    }
+}
+
+impl From<Polyline> for Vec<Point> {
+    /// Flattens the provided [`Polyline`] into its vertices, `start` first.
+    fn from(p: Polyline) -> Self {
+        let mut vertices = p.points;
+        vertices.insert(0, p.start);
+        vertices
+    }
+}
+
+impl IntoIterator for Polyline {
+    type Item = Point;
+    type IntoIter = iter::Chain<iter::Once<Point>, vec::IntoIter<Point>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        iter::once(self.start).chain(self.points)
+    }
+}
+
+/// Returns the `(min, max)` corners of the axis-aligned bounding box of the
+/// `first` vertex and the `rest` ones.
+fn bounds<'a>(first: Point, rest: impl IntoIterator<Item = &'a Point>) -> (Point, Point) {
+    rest.into_iter().fold((first, first), |(min, max), &p| {
+        (min.component_min(p), max.component_max(p))
+    })
+}
+
+/// Returns the arithmetic mean of the provided non-empty `vertices`, rounded
+/// to the nearest integer, with halves rounded away from zero.
+fn mean<'a>(vertices: impl IntoIterator<Item = &'a Point>) -> Point {
+    let (sum_x, sum_y, n) = vertices
+        .into_iter()
+        .fold((0_i64, 0_i64, 0_i64), |(x, y, n), p| {
+            (x + i64::from(p.x), y + i64::from(p.y), n + 1)
+        });
+    // Mean of `i32` values always lies within the `i32` range.
+    let mean = |sum: i64| ((sum + sum.signum() * n / 2) / n) as i32;
+    Point {
+        x: mean(sum_x),
+        y: mean(sum_y),
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use super::*;
+
+    mod len {
+        use super::*;
+
+        #[test]
+        fn counts_start_vertex() {
+            assert_eq!(Polyline::new(Point::default()).len(), 1);
+        }
+
+        #[test]
+        fn counts_rest_vertices() {
+            let p = Polyline::with_points(Point::default(), vec![Point { x: 1, y: 1 }; 3]);
+
+            assert_eq!(p.len(), 4);
+        }
+
+        #[test]
+        fn grows_on_push() {
+            let mut p = Polyline::new(Point::default());
+
+            p.push(Point { x: 1, y: 2 });
+            p.push(Point { x: 3, y: 4 });
+
+            assert_eq!(p.len(), 3);
+        }
+    }
+
+    mod insert {
+        use super::*;
+
+        fn polyline() -> Polyline {
+            Polyline::with_points(Point { x: 1, y: 1 }, vec![Point { x: 2, y: 2 }])
+        }
+
+        fn xs(p: &Polyline) -> Vec<i32> {
+            p.iter().map(|p| p.x).collect()
+        }
+
+        #[test]
+        fn inserts_new_start() {
+            let mut p = polyline();
+
+            p.insert(0, Point { x: 0, y: 0 });
+
+            assert_eq!(p.len(), 3);
+            assert_eq!(p.start(), Point { x: 0, y: 0 });
+            assert_eq!(xs(&p), [0, 1, 2]);
+        }
+
+        #[test]
+        fn inserts_in_middle() {
+            let mut p = polyline();
+
+            p.insert(1, Point { x: 9, y: 9 });
+
+            assert_eq!(p.len(), 3);
+            assert_eq!(xs(&p), [1, 9, 2]);
+        }
+
+        #[test]
+        fn inserts_at_end() {
+            let mut p = polyline();
+
+            p.insert(2, Point { x: 3, y: 3 });
+            p.insert(3, Point { x: 4, y: 4 });
+
+            assert_eq!(p.len(), 4);
+            assert_eq!(xs(&p), [1, 2, 3, 4]);
+        }
+
+        #[test]
+        #[should_panic = "insertion index (is 3) should be <= len (is 2)"]
+        fn panics_on_out_of_range_index() {
+            polyline().insert(3, Point::default());
+        }
+    }
+
+    mod remove {
+        use super::*;
+
+        fn polyline() -> Polyline {
+            (1..=3).map(|i| Point { x: i, y: i }).collect()
+        }
+
+        #[test]
+        fn promotes_next_vertex_to_start() {
+            let mut p = polyline();
+
+            assert_eq!(p.remove(0), Some(Point { x: 1, y: 1 }));
+            assert_eq!(p.start(), Point { x: 2, y: 2 });
+            assert_eq!(p, (2..=3).map(|i| Point { x: i, y: i }).collect());
+        }
+
+        #[test]
+        fn removes_middle_vertex() {
+            let mut p = polyline();
+
+            assert_eq!(p.remove(1), Some(Point { x: 2, y: 2 }));
+            assert_eq!(
+                p,
+                Polyline::with_points(Point { x: 1, y: 1 }, vec![Point { x: 3, y: 3 }]),
+            );
+        }
+
+        #[test]
+        fn refuses_to_remove_only_vertex() {
+            let mut p = Polyline::new(Point { x: 1, y: 1 });
+
+            assert_eq!(p.remove(0), None);
+            assert_eq!(p, Polyline::new(Point { x: 1, y: 1 }));
+        }
+
+        #[test]
+        fn ignores_out_of_range_index() {
+            let mut p = polyline();
+
+            assert_eq!(p.remove(3), None);
+            assert_eq!(p, polyline());
+        }
+    }
+
+    mod append {
+        use super::*;
+
+        fn polylines() -> (Polyline, Polyline) {
+            (
+                Polyline::with_points(Point { x: 0, y: 0 }, vec![Point { x: 1, y: 1 }]),
+                Polyline::with_points(
+                    Point { x: 2, y: 2 },
+                    vec![Point { x: 3, y: 3 }, Point { x: 4, y: 4 }],
+                ),
+            )
+        }
+
+        #[test]
+        fn sums_lengths() {
+            let (mut a, b) = polylines();
+
+            a.append(&b);
+
+            assert_eq!(a.len(), 2 + 3);
+            assert_eq!(polylines().0.concat(b).len(), 2 + 3);
+        }
+
+        #[test]
+        fn keeps_own_start() {
+            let (a, b) = polylines();
+
+            let joined = a.concat(b);
+
+            assert_eq!(joined.start(), Point { x: 0, y: 0 });
+            assert_eq!(joined, (0..5).map(|i| Point { x: i, y: i }).collect());
+        }
+    }
+
+    mod closed {
+        use super::*;
+
+        fn open() -> Polyline {
+            Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![Point { x: 3, y: 0 }, Point { x: 3, y: 3 }],
+            )
+        }
+
+        #[test]
+        fn appends_start() {
+            let p = open().closed();
+
+            assert_eq!(p.len(), open().len() + 1);
+            assert_eq!(p.iter().last(), Some(&p.start()));
+        }
+
+        #[test]
+        fn is_idempotent() {
+            assert_eq!(open().closed().closed(), open().closed());
+        }
+
+        #[test]
+        fn detects_closed_loop() {
+            let mut p = open();
+            assert!(!p.is_closed());
+
+            p.push(Point { x: 0, y: 0 });
+
+            assert!(p.is_closed());
+            assert!(open().closed().is_closed());
+            assert!(Polyline::new(Point::default()).is_closed());
+        }
+    }
+
+    mod split_at {
+        use super::*;
+
+        fn polyline() -> Polyline {
+            (0..5).map(|i| Point { x: i, y: -i }).collect()
+        }
+
+        #[test]
+        fn shares_cut_vertex() {
+            let (head, tail) = polyline().split_at(2);
+
+            assert_eq!(head, (0..=2).map(|i| Point { x: i, y: -i }).collect());
+            assert_eq!(tail, (2..5).map(|i| Point { x: i, y: -i }).collect());
+            assert_eq!(head.concat(tail).len(), polyline().len() + 1);
+        }
+
+        #[test]
+        fn keeps_both_pieces_non_empty() {
+            let p = polyline();
+
+            for index in 0..p.len() + 2 {
+                let (head, tail) = p.split_at(index);
+
+                assert!(head.len() >= 1 && tail.len() >= 1);
+                assert_eq!(head.len() + tail.len(), p.len() + 1);
+            }
+        }
+
+        #[test]
+        fn clamps_out_of_range_index() {
+            let (head, tail) = polyline().split_at(100);
+
+            assert_eq!(head, polyline());
+            assert_eq!(tail, Polyline::new(Point { x: 4, y: -4 }));
+        }
+
+        #[test]
+        fn splits_single_vertex() {
+            let p = Polyline::new(Point { x: 1, y: 1 });
+
+            assert_eq!(p.split_at(0), (p.clone(), p.clone()));
+        }
+    }
+
+    mod split_by_angle {
+        use std::f64::consts::FRAC_PI_4;
+
+        use super::*;
+
+        #[test]
+        fn keeps_smooth_curve_whole() {
+            let p: Polyline = (0..10)
+                .map(|i| Point {
+                    x: i * 10,
+                    y: i * i,
+                })
+                .collect();
+
+            assert_eq!(p.split_by_angle(FRAC_PI_4), [p]);
+        }
+
+        #[test]
+        fn splits_l_shape_at_corner() {
+            let p: Polyline = [(0, 0), (5, 0), (10, 0), (10, 5), (10, 10)]
+                .into_iter()
+                .map(Point::from)
+                .collect();
+
+            assert_eq!(
+                p.split_by_angle(FRAC_PI_4),
+                [
+                    Polyline::with_points(
+                        Point { x: 0, y: 0 },
+                        vec![Point { x: 5, y: 0 }, Point { x: 10, y: 0 }],
+                    ),
+                    Polyline::with_points(
+                        Point { x: 10, y: 0 },
+                        vec![Point { x: 10, y: 5 }, Point { x: 10, y: 10 }],
+                    ),
+                ],
+            );
+            assert_eq!(p.reversed().split_by_angle(FRAC_PI_4).len(), 2);
+        }
+
+        #[test]
+        fn splits_at_every_sharp_corner() {
+            let p: Polyline = (0..6).map(|i| Point { x: i, y: i % 2 * 5 }).collect();
+
+            let pieces = p.split_by_angle(FRAC_PI_4);
+
+            assert_eq!(pieces.len(), 5);
+            assert!(pieces.iter().all(|piece| piece.len() == 2));
+        }
+
+        #[test]
+        fn keeps_short_polyline_whole() {
+            let p = Polyline::with_points(Point::default(), vec![Point { x: 1, y: 1 }]);
+
+            assert_eq!(p.split_by_angle(0.0), [p]);
+            assert_eq!(
+                Polyline::new(Point::default()).split_by_angle(0.0),
+                [Polyline::new(Point::default())],
+            );
+        }
+    }
+
+    mod dedup_consecutive {
+        use super::*;
+
+        #[test]
+        fn removes_repeated_vertices() {
+            let (a, b, c) = (
+                Point { x: 0, y: 0 },
+                Point { x: 1, y: 1 },
+                Point { x: 2, y: 0 },
+            );
+            let mut p = Polyline::with_points(a, vec![a, b, b, b, c, a, a]);
+
+            p.dedup_consecutive();
+
+            assert_eq!(p, Polyline::with_points(a, vec![b, c, a]));
+        }
+
+        #[test]
+        fn collapses_identical_vertices_into_start() {
+            let a = Point { x: 5, y: 5 };
+            let mut p = Polyline::with_points(a, vec![a; 4]);
+
+            p.dedup_consecutive();
+
+            assert_eq!(p, Polyline::new(a));
+        }
+    }
+
+    mod debug {
+        use super::*;
+
+        #[test]
+        fn renders_vertex_list() {
+            let p = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![Point { x: 1, y: -2 }, Point { x: 30, y: 4 }],
+            );
+
+            assert_eq!(format!("{p:?}"), "Polyline[(0,0) -> (1,-2) -> (30,4)]");
+        }
+
+        #[test]
+        fn renders_single_vertex() {
+            let p = Polyline::new(Point { x: 7, y: 8 });
+
+            assert_eq!(format!("{p:?}"), "Polyline[(7,8)]");
+        }
+    }
+
+    mod same_path_as {
+        use super::*;
+
+        fn polyline() -> Polyline {
+            Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![Point { x: 1, y: 2 }, Point { x: 3, y: 1 }],
+            )
+        }
+
+        #[test]
+        fn matches_identical() {
+            assert!(polyline().same_path_as(&polyline()));
+        }
+
+        #[test]
+        fn matches_reversed() {
+            let reversed = polyline().reversed();
+
+            assert!(polyline().same_path_as(&reversed));
+            assert!(reversed.same_path_as(&polyline()));
+            assert_ne!(polyline(), reversed);
+        }
+
+        #[test]
+        fn differs_from_other_path() {
+            let mut other = polyline();
+            other.push(Point { x: 0, y: 0 });
+
+            assert!(!polyline().same_path_as(&other));
+            assert!(!polyline().same_path_as(&Polyline::new(Point::default())));
+        }
+    }
+
+    mod approx_eq {
+        use super::*;
+
+        fn polyline() -> Polyline {
+            Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![Point { x: 3, y: 4 }, Point { x: -5, y: 6 }],
+            )
+        }
+
+        #[test]
+        fn tolerates_differences_within_tol() {
+            let mut other = polyline();
+            other.translate(Point { x: 1, y: -1 });
+
+            assert!(polyline().approx_eq(&other, 1));
+            assert!(!polyline().approx_eq(&other, 0));
+        }
+
+        #[test]
+        fn matches_eq_with_zero_tol() {
+            assert!(polyline().approx_eq(&polyline(), 0));
+            assert!(!polyline().approx_eq(&polyline(), -1));
+        }
+
+        #[test]
+        fn rejects_different_len() {
+            let mut other = polyline();
+            other.push(Point { x: -5, y: 6 });
+
+            assert!(!polyline().approx_eq(&other, 100));
+        }
+
+        #[test]
+        fn compares_extreme_coordinates() {
+            let min = Polyline::new(Point { x: i32::MIN, y: 0 });
+            let max = Polyline::new(Point { x: i32::MAX, y: 0 });
+
+            assert!(!min.approx_eq(&max, i32::MAX));
+            assert!(min.approx_eq(&min, 0));
+        }
+    }
+
+    mod display {
+        use super::*;
+
+        #[test]
+        fn renders_vertices_without_type_name() {
+            let p = Polyline::with_points(Point { x: 0, y: 0 }, vec![Point { x: 1, y: -2 }]);
+
+            assert_eq!(p.to_string(), "(0, 0) -> (1, -2)");
+            assert_eq!(format!("{p:?}"), "Polyline[(0,0) -> (1,-2)]");
+        }
+
+        #[test]
+        fn renders_single_vertex() {
+            assert_eq!(Polyline::new(Point { x: 7, y: 8 }).to_string(), "(7, 8)");
+        }
+    }
+
+    mod eq {
+        use std::collections::HashMap;
+
+        use super::*;
+
+        fn vertices() -> [Point; 3] {
+            [
+                Point { x: 0, y: 0 },
+                Point { x: 1, y: 2 },
+                Point { x: 3, y: 4 },
+            ]
+        }
+
+        #[test]
+        fn ignores_construction_path() {
+            let [a, b, c] = vertices();
+
+            let collected: Polyline = vertices().into_iter().collect();
+            let mut pushed = Polyline::new(a);
+            pushed.push(b);
+            pushed.push(c);
+
+            assert_eq!(collected, Polyline::with_points(a, vec![b, c]));
+            assert_eq!(collected, pushed);
+        }
+
+        #[test]
+        fn distinguishes_vertex_order() {
+            let [a, b, c] = vertices();
+
+            assert_ne!(
+                Polyline::with_points(a, vec![b, c]),
+                Polyline::with_points(a, vec![c, b]),
+            );
+            assert_ne!(
+                Polyline::with_points(a, vec![b]),
+                Polyline::with_points(b, vec![a])
+            );
+        }
+
+        #[test]
+        #[expect(
+            clippy::mutable_key_type,
+            reason = "memoized length is ignored by `Hash`"
+        )]
+        fn hashes_as_map_key() {
+            let [a, b, c] = vertices();
+            let mut map = HashMap::new();
+
+            map.insert(Polyline::with_points(a, vec![b, c]), "abc");
+
+            assert_eq!(map.get(&vertices().into_iter().collect()), Some(&"abc"));
+            assert_eq!(map.get(&Polyline::new(a)), None);
+        }
+    }
+
+    mod iter {
+        use super::*;
+
+        fn polylines() -> Vec<Polyline> {
+            let mut pushed = Polyline::new(Point { x: 1, y: 1 });
+            pushed.push(Point { x: 2, y: 2 });
+
+            vec![
+                Polyline::new(Point::default()),
+                Polyline::with_points(Point::default(), vec![Point { x: 1, y: 1 }; 5]),
+                pushed,
+            ]
+        }
+
+        #[test]
+        fn visits_every_vertex() {
+            for p in polylines() {
+                assert_eq!(p.iter().count(), p.len());
+                assert_eq!(p.clone().into_iter().count(), p.len());
+            }
+        }
+
+        #[test]
+        fn reports_exact_size_hint() {
+            for p in polylines() {
+                assert_eq!(p.iter().size_hint(), (p.len(), Some(p.len())));
+                assert_eq!(p.clone().into_iter().size_hint(), (p.len(), Some(p.len())));
+            }
+        }
+
+        #[test]
+        fn yields_start_first() {
+            let p = Polyline::with_points(Point { x: 1, y: 2 }, vec![Point { x: 3, y: 4 }]);
+
+            let xs: Vec<_> = p.iter().map(|p| p.x).collect();
+            assert_eq!(xs, [1, 3]);
+
+            let ys: Vec<_> = p.into_iter().map(|p| p.y).collect();
+            assert_eq!(ys, [2, 4]);
+        }
+    }
+
+    mod from_iter {
+        use super::*;
+
+        #[test]
+        fn collects_single_vertex() {
+            let p: Polyline = [Point { x: 1, y: 2 }].into_iter().collect();
+
+            assert_eq!(p.len(), 1);
+            assert_eq!(p.start().x, 1);
+        }
+
+        #[test]
+        fn collects_multiple_vertices_in_order() {
+            let p: Polyline = (0..4).map(|i| Point { x: i, y: -i }).collect();
+
+            assert_eq!(p.len(), 4);
+            assert_eq!(p.start().x, 0);
+            assert_eq!(p.iter().map(|p| p.y).collect::<Vec<_>>(), [0, -1, -2, -3]);
+        }
+
+        #[test]
+        #[should_panic = "cannot build Polyline from empty iterator"]
+        fn panics_on_empty_iterator() {
+            let _: Polyline = std::iter::empty().collect();
+        }
+    }
+
+    mod total_length {
+        use super::*;
+
+        #[test]
+        fn is_zero_for_single_vertex() {
+            assert_eq!(Polyline::new(Point { x: 5, y: 5 }).total_length(), 0.0);
+        }
+
+        #[test]
+        fn sums_l_shaped_path() {
+            let p = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![Point { x: 3, y: 0 }, Point { x: 3, y: 4 }],
+            );
+
+            assert_eq!(p.total_length(), 7.0);
+        }
+
+        #[test]
+        fn includes_segment_from_start() {
+            let p = Polyline::with_points(Point { x: 0, y: 0 }, vec![Point { x: 3, y: 4 }]);
+
+            assert_eq!(p.total_length(), 5.0);
+        }
+
+        #[test]
+        fn memoizes_until_mutation() {
+            let mut p = Polyline::with_points(Point { x: 0, y: 0 }, vec![Point { x: 3, y: 4 }]);
+            assert_eq!(p.length.get(), None);
+
+            assert_eq!(p.total_length(), 5.0);
+            assert_eq!(p.length.get(), Some(&5.0));
+            assert_eq!(p.total_length(), 5.0);
+
+            p.push(Point { x: 3, y: 0 });
+            assert_eq!(p.length.get(), None);
+            assert_eq!(p.total_length(), 9.0);
+
+            for mutate in [
+                |p: &mut Polyline| p.translate(Point { x: 1, y: 1 }),
+                |p: &mut Polyline| p.insert(1, Point { x: 0, y: 4 }),
+                |p: &mut Polyline| _ = p.remove(0),
+                |p: &mut Polyline| p.scale(2),
+            ] {
+                let _ = p.total_length();
+                mutate(&mut p);
+                assert_eq!(p.length.get(), None);
+            }
+            assert_eq!(
+                p.total_length(),
+                p.segments().map(|(a, b)| a.distance_to(b)).sum(),
+            );
+        }
+
+        #[test]
+        fn is_ignored_by_equality() {
+            let p = Polyline::with_points(Point { x: 0, y: 0 }, vec![Point { x: 3, y: 4 }]);
+            let cached = p.clone();
+            let _ = cached.total_length();
+
+            assert_eq!(p, cached);
+            assert_eq!(p.clone(), cached.clone());
+        }
+    }
+
+    mod total_squared_segment_lengths {
+        use super::*;
+
+        #[test]
+        fn is_zero_for_single_vertex() {
+            let p = Polyline::new(Point { x: 5, y: 5 });
+
+            assert_eq!(p.total_squared_segment_lengths(), 0);
+        }
+
+        #[test]
+        fn sums_squared_lengths() {
+            let p = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![
+                    Point { x: 3, y: 4 },
+                    Point { x: 3, y: -1 },
+                    Point { x: 4, y: 0 },
+                ],
+            );
+
+            assert_eq!(p.total_squared_segment_lengths(), 25 + 25 + 2);
+        }
+
+        #[test]
+        fn ranks_uneven_path_higher() {
+            let even = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![Point { x: 5, y: 0 }, Point { x: 10, y: 0 }],
+            );
+            let uneven = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![Point { x: 9, y: 0 }, Point { x: 10, y: 0 }],
+            );
+
+            assert_eq!(even.total_length(), uneven.total_length());
+            assert!(even.total_squared_segment_lengths() < uneven.total_squared_segment_lengths());
+        }
+
+        #[test]
+        fn stays_exact_for_large_coordinates() {
+            let p = Polyline::with_points(Point { x: i32::MIN, y: 0 }, vec![Point { x: 0, y: 0 }]);
+
+            assert_eq!(p.total_squared_segment_lengths(), 1 << 62);
+        }
+    }
+
+    mod bounding_box {
+        use super::*;
+
+        #[test]
+        fn degenerates_to_single_point() {
+            let (min, max) = Polyline::new(Point { x: 2, y: -3 }).bounding_box();
+
+            assert_eq!((min.x, min.y), (2, -3));
+            assert_eq!((max.x, max.y), (2, -3));
+        }
+
+        #[test]
+        fn computes_each_axis_independently() {
+            let p = Polyline::with_points(
+                Point { x: -1, y: 5 },
+                vec![Point { x: 4, y: -7 }, Point { x: -6, y: 2 }],
+            );
+
+            let (min, max) = p.bounding_box();
+
+            assert_eq!((min.x, min.y), (-6, -7));
+            assert_eq!((max.x, max.y), (4, 5));
+        }
+    }
+
+    mod monotonic {
+        use super::*;
+
+        #[test]
+        fn accepts_strictly_increasing() {
+            let p: Polyline = (0..5).map(|i| Point { x: i, y: i * i }).collect();
+
+            assert!(p.is_monotonic_x());
+            assert!(p.is_monotonic_y());
+        }
+
+        #[test]
+        fn accepts_non_decreasing() {
+            let p: Polyline = [(0, 0), (2, 1), (2, 5), (3, 5)]
+                .into_iter()
+                .map(Point::from)
+                .collect();
+
+            assert!(p.is_monotonic_x());
+            assert!(p.is_monotonic_y());
+        }
+
+        #[test]
+        fn rejects_back_tracking() {
+            let p: Polyline = [(0, 0), (4, 1), (3, 2)]
+                .into_iter()
+                .map(Point::from)
+                .collect();
+
+            assert!(!p.is_monotonic_x());
+            assert!(p.is_monotonic_y());
+            assert!(!p.reversed().is_monotonic_y());
+        }
+
+        #[test]
+        fn accepts_single_vertex() {
+            let p = Polyline::new(Point { x: 1, y: 1 });
+
+            assert!(p.is_monotonic_x());
+            assert!(p.is_monotonic_y());
+        }
+    }
+
+    mod value_at_x {
+        use super::*;
+
+        fn polyline() -> Polyline {
+            [(0, 0), (4, 2), (4, 6), (10, 0)]
+                .into_iter()
+                .map(Point::from)
+                .collect()
+        }
+
+        #[test]
+        fn interpolates_between_vertices() {
+            assert_eq!(polyline().value_at_x(1), Some(0.5));
+            assert_eq!(polyline().value_at_x(7), Some(3.0));
+        }
+
+        #[test]
+        fn returns_exact_vertex_value() {
+            assert_eq!(polyline().value_at_x(0), Some(0.0));
+            assert_eq!(polyline().value_at_x(10), Some(0.0));
+            assert_eq!(polyline().value_at_x(4), Some(2.0));
+        }
+
+        #[test]
+        fn returns_none_outside_domain() {
+            assert_eq!(polyline().value_at_x(-1), None);
+            assert_eq!(polyline().value_at_x(11), None);
+            assert_eq!(Polyline::new(Point { x: 3, y: 5 }).value_at_x(2), None);
+        }
+
+        #[test]
+        fn returns_none_for_non_monotonic() {
+            assert_eq!(polyline().reversed().value_at_x(1), None);
+        }
+
+        #[test]
+        fn evaluates_single_vertex() {
+            assert_eq!(Polyline::new(Point { x: 3, y: 5 }).value_at_x(3), Some(5.0));
+        }
+    }
+
+    mod smooth {
+        use super::*;
+
+        #[test]
+        fn flattens_spike_keeping_anchors() {
+            let p = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![Point { x: 5, y: 10 }, Point { x: 10, y: 0 }],
+            );
+
+            assert_eq!(
+                p.smooth(3),
+                Polyline::with_points(
+                    Point { x: 0, y: 0 },
+                    vec![Point { x: 5, y: 3 }, Point { x: 10, y: 0 }],
+                ),
+            );
+        }
+
+        #[test]
+        fn averages_original_vertices() {
+            let p: Polyline = [0, 9, 0, 9, 0]
+                .into_iter()
+                .enumerate()
+                .map(|(x, y)| Point { x: x as i32, y })
+                .collect();
+
+            assert_eq!(
+                p.smooth(3).iter().map(|p| p.y).collect::<Vec<_>>(),
+                [0, 3, 6, 3, 0],
+            );
+            assert_eq!(
+                p.smooth(5).iter().map(|p| p.y).collect::<Vec<_>>(),
+                [0, 5, 4, 5, 0],
+            );
+        }
+
+        #[test]
+        fn keeps_unchanged_for_tiny_window() {
+            let p = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![Point { x: 5, y: 10 }, Point { x: 10, y: 0 }],
+            );
+
+            assert_eq!(p.smooth(0), p);
+            assert_eq!(p.smooth(1), p);
+        }
+    }
+
+    mod chaikin {
+        use super::*;
+
+        fn polyline() -> Polyline {
+            Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![Point { x: 40, y: 0 }, Point { x: 40, y: 40 }],
+            )
+        }
+
+        #[test]
+        fn cuts_corners_at_quarters() {
+            assert_eq!(
+                polyline().chaikin(1),
+                [(0, 0), (10, 0), (30, 0), (40, 10), (40, 30), (40, 40)]
+                    .into_iter()
+                    .map(Point::from)
+                    .collect(),
+            );
+        }
+
+        #[test]
+        fn doubles_len_per_iteration() {
+            for iterations in 0..5 {
+                assert_eq!(polyline().chaikin(iterations).len(), 3 << iterations);
+            }
+        }
+
+        #[test]
+        fn preserves_endpoints() {
+            let p = polyline().chaikin(4);
+
+            assert_eq!(p.iter().next(), Some(&Point { x: 0, y: 0 }));
+            assert_eq!(p.iter().last(), Some(&Point { x: 40, y: 40 }));
+        }
+
+        #[test]
+        fn keeps_single_vertex() {
+            let p = Polyline::new(Point { x: 1, y: 2 });
+
+            assert_eq!(p.chaikin(3), p);
+        }
+    }
+
+    mod signed_area {
+        use super::*;
+
+        fn square() -> Polyline {
+            Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![
+                    Point { x: 1, y: 0 },
+                    Point { x: 1, y: 1 },
+                    Point { x: 0, y: 1 },
+                ],
+            )
+        }
+
+        #[test]
+        fn is_positive_for_counter_clockwise() {
+            assert_eq!(square().signed_area(), 1.0);
+        }
+
+        #[test]
+        fn is_negative_for_clockwise() {
+            assert_eq!(square().reversed().signed_area(), -1.0);
+        }
+
+        #[test]
+        fn is_zero_for_degenerate() {
+            assert_eq!(Polyline::new(Point { x: 3, y: 3 }).signed_area(), 0.0);
+            let line = Polyline::with_points(Point { x: 0, y: 0 }, vec![Point { x: 5, y: 5 }]);
+            assert_eq!(line.signed_area(), 0.0);
+        }
+
+        #[test]
+        fn keeps_halves_of_triangle() {
+            let p = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![Point { x: 3, y: 0 }, Point { x: 0, y: 1 }],
+            );
+
+            assert_eq!(p.signed_area(), 1.5);
+        }
+    }
+
+    mod turn_angles {
+        use std::f64::consts::FRAC_PI_2;
+
+        use super::*;
+
+        #[test]
+        fn are_zero_along_straight_path() {
+            let p: Polyline = (0..5).map(|i| Point { x: i, y: 2 * i }).collect();
+
+            assert_eq!(p.turn_angles(), [0.0; 3]);
+        }
+
+        #[test]
+        fn are_signed_at_right_angle_corners() {
+            let left = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![Point { x: 5, y: 0 }, Point { x: 5, y: 5 }],
+            );
+            let right = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![Point { x: 5, y: 0 }, Point { x: 5, y: -5 }],
+            );
+
+            assert_eq!(left.turn_angles(), [FRAC_PI_2]);
+            assert_eq!(right.turn_angles(), [-FRAC_PI_2]);
+        }
+
+        #[test]
+        fn wrap_across_heading_zero() {
+            let p = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![Point { x: 5, y: -5 }, Point { x: 10, y: 0 }],
+            );
+
+            let [turn] = p.turn_angles()[..] else {
+                panic!("expected a single turn angle");
+            };
+            assert!((turn - FRAC_PI_2).abs() < 1e-12);
+        }
+
+        #[test]
+        fn are_empty_without_interior_vertices() {
+            assert!(Polyline::new(Point::default()).turn_angles().is_empty());
+            let p = Polyline::with_points(Point::default(), vec![Point { x: 1, y: 1 }]);
+            assert!(p.turn_angles().is_empty());
+        }
+    }
+
+    mod centroid {
+        use super::*;
+
+        #[test]
+        fn is_center_of_square() {
+            let p = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![
+                    Point { x: 4, y: 0 },
+                    Point { x: 4, y: 4 },
+                    Point { x: 0, y: 4 },
+                ],
+            );
+
+            assert_eq!(p.centroid(), Point { x: 2, y: 2 });
+        }
+
+        #[test]
+        fn is_single_vertex_itself() {
+            let p = Polyline::new(Point { x: -3, y: 7 });
+
+            assert_eq!(p.centroid(), Point { x: -3, y: 7 });
+        }
+
+        #[test]
+        fn rounds_halves_away_from_zero() {
+            let p = Polyline::with_points(Point { x: 0, y: 0 }, vec![Point { x: 3, y: -3 }]);
+
+            assert_eq!(p.centroid(), Point { x: 2, y: -2 });
+        }
+
+        #[test]
+        fn does_not_overflow_on_i32_extremes() {
+            let max = Point {
+                x: i32::MAX,
+                y: i32::MAX,
+            };
+            let p = Polyline::with_points(max, vec![max, max]);
+
+            assert_eq!(p.centroid(), max);
+        }
+    }
+
+    mod rotate_start {
+        use super::*;
+
+        fn square(vertices: [(i32, i32); 4]) -> Polyline {
+            vertices
+                .into_iter()
+                .map(Point::from)
+                .collect::<Polyline>()
+                .closed()
+        }
+
+        #[test]
+        fn keeps_closed_polyline_closed() {
+            let mut p = square([(0, 0), (1, 0), (1, 1), (0, 1)]);
+
+            p.rotate_start(1);
+
+            assert_eq!(p, square([(1, 0), (1, 1), (0, 1), (0, 0)]));
+            assert!(p.is_closed());
+        }
+
+        #[test]
+        fn rotates_open_polyline_as_whole() {
+            let mut p: Polyline = (0..4).map(|i| Point { x: i, y: 0 }).collect();
+
+            p.rotate_start(3);
+
+            assert_eq!(
+                p,
+                [3, 0, 1, 2]
+                    .into_iter()
+                    .map(|x| Point { x, y: 0 })
+                    .collect()
+            );
+        }
+
+        #[test]
+        fn wraps_around_vertices_count() {
+            let p = square([(0, 0), (1, 0), (1, 1), (0, 1)]);
+
+            for by in [0, 4, 8] {
+                let mut rotated = p.clone();
+                rotated.rotate_start(by);
+
+                assert_eq!(rotated, p);
+            }
+            let (mut a, mut b) = (p.clone(), p);
+            a.rotate_start(2);
+            b.rotate_start(6);
+            assert_eq!(a, b);
+        }
+
+        #[test]
+        fn keeps_single_vertex() {
+            let mut p = Polyline::new(Point { x: 1, y: 2 });
+
+            p.rotate_start(5);
+
+            assert_eq!(p, Polyline::new(Point { x: 1, y: 2 }));
+        }
+    }
+
+    mod canonical_start {
+        use super::*;
+
+        #[test]
+        fn equalizes_rotations_of_closed_square() {
+            let mut a: Polyline = [(1, 1), (0, 1), (0, 0), (1, 0), (1, 1)]
+                .into_iter()
+                .map(Point::from)
+                .collect();
+            let mut b: Polyline = [(0, 1), (0, 0), (1, 0), (1, 1), (0, 1)]
+                .into_iter()
+                .map(Point::from)
+                .collect();
+            assert_ne!(a, b);
+
+            a.canonical_start();
+            b.canonical_start();
+
+            assert_eq!(a, b);
+            assert_eq!(a.start(), Point { x: 0, y: 0 });
+            assert!(a.is_closed());
+        }
+
+        #[test]
+        fn starts_open_polyline_at_smallest_vertex() {
+            let mut p: Polyline = [(3, 0), (-1, 5), (-1, 2), (0, 0)]
+                .into_iter()
+                .map(Point::from)
+                .collect();
+
+            p.canonical_start();
+
+            assert_eq!(
+                p,
+                [(-1, 2), (0, 0), (3, 0), (-1, 5)]
+                    .into_iter()
+                    .map(Point::from)
+                    .collect(),
+            );
+        }
+    }
+
+    mod reverse {
+        use super::*;
+
+        #[test]
+        fn makes_last_vertex_start() {
+            let mut p: Polyline = (0..4).map(|i| Point { x: i, y: 0 }).collect();
+
+            p.reverse();
+
+            assert_eq!(p, (0..4).rev().map(|i| Point { x: i, y: 0 }).collect());
+        }
+
+        #[test]
+        fn keeps_single_vertex() {
+            let p = Polyline::new(Point { x: 1, y: 2 });
+
+            assert_eq!(p.reversed(), p);
+        }
+
+        #[test]
+        fn is_involutive() {
+            for p in [
+                Polyline::new(Point::default()),
+                Polyline::with_points(Point::default(), vec![Point { x: 1, y: 1 }]),
+                (0..7).map(|i| Point { x: i, y: i * i }).collect(),
+            ] {
+                assert_eq!(p.reversed().reversed(), p);
+            }
+        }
+    }
+
+    mod transform {
+        use std::f64::consts::FRAC_PI_2;
+
+        use super::*;
+
+        fn polyline() -> Polyline {
+            Polyline::with_points(
+                Point { x: 1, y: -2 },
+                vec![Point { x: 3, y: 4 }, Point { x: -5, y: 6 }],
+            )
+        }
+
+        #[test]
+        fn translates_every_vertex() {
+            let mut p = polyline();
+
+            p.translate(Point { x: 10, y: 20 });
+
+            assert_eq!(
+                p,
+                Polyline::with_points(
+                    Point { x: 11, y: 18 },
+                    vec![Point { x: 13, y: 24 }, Point { x: 5, y: 26 }],
+                ),
+            );
+        }
+
+        #[test]
+        fn translates_invertibly() {
+            let offset = Point { x: 7, y: -9 };
+            let mut p = polyline();
+
+            p.translate(offset);
+            p.translate(-offset);
+
+            assert_eq!(p, polyline());
+        }
+
+        #[test]
+        fn scales_every_coordinate() {
+            let mut p = polyline();
+
+            p.scale(-2);
+
+            assert_eq!(
+                p,
+                Polyline::with_points(
+                    Point { x: -2, y: 4 },
+                    vec![Point { x: -6, y: -8 }, Point { x: 10, y: -12 }],
+                ),
+            );
+        }
+
+        #[test]
+        fn scales_by_one_as_no_op() {
+            let mut p = polyline();
+
+            p.scale(1);
+
+            assert_eq!(p, polyline());
+        }
+
+        #[test]
+        fn transforms_every_vertex() {
+            let t = Affine2::rotation(FRAC_PI_2).then(Affine2::translation(Point { x: 1, y: 1 }));
+
+            assert_eq!(
+                polyline().transformed(&t),
+                Polyline::with_points(
+                    Point { x: 3, y: 2 },
+                    vec![Point { x: -3, y: 4 }, Point { x: -5, y: -4 }],
+                ),
+            );
+        }
+
+        #[test]
+        fn maps_every_vertex_in_order() {
+            let mut visited = vec![];
+
+            let p = polyline().map(|p| {
+                visited.push(p);
+                Point { x: p.y, y: p.x }
+            });
+
+            assert_eq!(visited, polyline().iter().copied().collect::<Vec<_>>());
+            assert_eq!(
+                p,
+                Polyline::with_points(
+                    Point { x: -2, y: 1 },
+                    vec![Point { x: 4, y: 3 }, Point { x: 6, y: -5 }],
+                ),
+            );
+        }
+    }
+
+    mod nearest_vertex {
+        use super::*;
+
+        fn polyline() -> Polyline {
+            Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![Point { x: 10, y: 0 }, Point { x: 10, y: 10 }],
+            )
+        }
+
+        #[test]
+        fn returns_vertex_under_query() {
+            assert_eq!(
+                polyline().nearest_vertex(Point { x: 10, y: 0 }),
+                Point { x: 10, y: 0 }
+            );
+        }
+
+        #[test]
+        fn returns_closest_vertex() {
+            assert_eq!(
+                polyline().nearest_vertex(Point { x: 9, y: 7 }),
+                Point { x: 10, y: 10 }
+            );
+            assert_eq!(
+                polyline().nearest_vertex(Point { x: -5, y: -5 }),
+                Point { x: 0, y: 0 }
+            );
+        }
+
+        #[test]
+        fn prefers_first_vertex_on_tie() {
+            assert_eq!(
+                polyline().nearest_vertex(Point { x: 5, y: 0 }),
+                Point { x: 0, y: 0 }
+            );
+            assert_eq!(
+                polyline().nearest_vertex(Point { x: 20, y: 5 }),
+                Point { x: 10, y: 0 }
+            );
+        }
+    }
+
+    mod project {
+        use super::*;
+
+        fn polyline() -> Polyline {
+            Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![Point { x: 10, y: 0 }, Point { x: 10, y: 10 }],
+            )
+        }
+
+        #[test]
+        fn projects_onto_segment_middle() {
+            assert_eq!(
+                polyline().project(Point { x: 4, y: 3 }),
+                (Point { x: 4, y: 0 }, 4.0)
+            );
+            assert_eq!(
+                polyline().project(Point { x: 14, y: 7 }),
+                (Point { x: 10, y: 7 }, 17.0),
+            );
+        }
+
+        #[test]
+        fn rounds_projected_point() {
+            let p = Polyline::with_points(Point { x: 0, y: 0 }, vec![Point { x: 10, y: 10 }]);
+
+            let (closest, arc_length) = p.project(Point { x: 3, y: 6 });
+
+            assert_eq!(closest, Point { x: 5, y: 5 });
+            assert!((arc_length - 4.5 * 2_f64.sqrt()).abs() < 1e-9);
+        }
+
+        #[test]
+        fn projects_onto_endpoints() {
+            assert_eq!(
+                polyline().project(Point { x: -5, y: -5 }),
+                (Point { x: 0, y: 0 }, 0.0)
+            );
+            assert_eq!(
+                polyline().project(Point { x: 10, y: 25 }),
+                (Point { x: 10, y: 10 }, 20.0),
+            );
+        }
+
+        #[test]
+        fn projects_onto_corner_vertex() {
+            assert_eq!(
+                polyline().project(Point { x: 13, y: -2 }),
+                (Point { x: 10, y: 0 }, 10.0),
+            );
+        }
+
+        #[test]
+        fn handles_extreme_coordinates() {
+            let p = Polyline::with_points(
+                Point { x: i32::MIN, y: 0 },
+                vec![Point { x: i32::MAX, y: 0 }],
+            );
+
+            let (closest, arc_length) = p.project(Point::ORIGIN);
+
+            assert_eq!(closest, Point::ORIGIN);
+            assert!((arc_length - 2_f64.powi(31)).abs() < 1e-3);
+        }
+
+        #[test]
+        fn projects_onto_single_vertex() {
+            let p = Polyline::new(Point { x: 1, y: 2 });
+
+            assert_eq!(
+                p.project(Point { x: 7, y: -3 }),
+                (Point { x: 1, y: 2 }, 0.0)
+            );
+        }
+    }
+
+    mod subdivide {
+        use super::*;
+
+        #[test]
+        fn inserts_midpoints() {
+            let p = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![Point { x: 4, y: 0 }, Point { x: 4, y: 3 }],
+            );
+
+            let subdivided = p.subdivide();
+
+            assert_eq!(subdivided.len(), 2 * p.len() - 1);
+            assert_eq!(
+                subdivided,
+                Polyline::with_points(
+                    Point { x: 0, y: 0 },
+                    vec![
+                        Point { x: 2, y: 0 },
+                        Point { x: 4, y: 0 },
+                        Point { x: 4, y: 2 },
+                        Point { x: 4, y: 3 },
+                    ],
+                ),
+            );
+        }
+
+        #[test]
+        fn keeps_originals_at_even_positions() {
+            let p: Polyline = (0..6).map(|i| Point { x: i * i, y: -i }).collect();
+
+            let subdivided = p.subdivide();
+
+            assert_eq!(subdivided.len(), 2 * p.len() - 1);
+            assert!(p.iter().eq(subdivided.iter().step_by(2)));
+        }
+
+        #[test]
+        fn keeps_single_vertex() {
+            let p = Polyline::new(Point { x: 1, y: 1 });
+
+            assert_eq!(p.subdivide(), p);
+        }
+    }
+
+    mod densify {
+        use super::*;
+
+        #[test]
+        fn splits_long_segment_under_limit() {
+            let p = Polyline::with_points(Point { x: 0, y: 0 }, vec![Point { x: 10, y: 0 }]);
+
+            let densified = p.densify(3.0);
+
+            assert_eq!(densified.segments().count(), 4);
+            assert!(densified.segments().all(|(a, b)| a.distance_to(b) <= 3.0));
+            assert_eq!(
+                densified.iter().map(|p| p.x).collect::<Vec<_>>(),
+                [0, 3, 5, 8, 10]
+            );
+        }
+
+        #[test]
+        fn preserves_original_vertices() {
+            let p = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![Point { x: 2, y: 0 }, Point { x: 2, y: 9 }],
+            );
+
+            let densified = p.densify(3.0);
+
+            assert_eq!(densified.len(), 5);
+            for v in p.iter() {
+                assert!(densified.iter().any(|d| d == v));
+            }
+            assert_eq!(densified.simplify(0.0), p);
+        }
+
+        #[test]
+        fn keeps_short_segments() {
+            let p = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![Point { x: 0, y: 0 }, Point { x: 1, y: 1 }],
+            );
+
+            assert_eq!(p.densify(5.0), p);
+        }
+
+        #[test]
+        #[should_panic = "`max_len` must be positive"]
+        fn panics_on_non_positive_max_len() {
+            let _ = Polyline::new(Point::default()).densify(0.0);
+        }
+    }
+
+    mod point_at {
+        use super::*;
+
+        fn polyline() -> Polyline {
+            Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![Point { x: 10, y: 0 }, Point { x: 10, y: 10 }],
+            )
+        }
+
+        #[test]
+        fn returns_start_at_zero() {
+            assert_eq!(polyline().point_at(0.0), Point { x: 0, y: 0 });
+            assert_eq!(polyline().point_at(-5.0), Point { x: 0, y: 0 });
+        }
+
+        #[test]
+        fn returns_last_vertex_beyond_end() {
+            assert_eq!(polyline().point_at(20.0), Point { x: 10, y: 10 });
+            assert_eq!(polyline().point_at(100.0), Point { x: 10, y: 10 });
+        }
+
+        #[test]
+        fn interpolates_within_segment() {
+            let p = Polyline::with_points(Point { x: 0, y: 0 }, vec![Point { x: 8, y: 6 }]);
+
+            assert_eq!(p.point_at(5.0), Point { x: 4, y: 3 });
+            assert_eq!(polyline().point_at(15.0), Point { x: 10, y: 5 });
+        }
+
+        #[test]
+        fn returns_single_vertex() {
+            let p = Polyline::new(Point { x: 3, y: 4 });
+
+            assert_eq!(p.point_at(1.0), Point { x: 3, y: 4 });
+        }
+    }
+
+    mod iter_with_arclen {
+        use super::*;
+
+        #[test]
+        fn pairs_vertices_with_arc_lengths() {
+            let p = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![
+                    Point { x: 3, y: 4 },
+                    Point { x: 3, y: 4 },
+                    Point { x: 3, y: 6 },
+                ],
+            );
+
+            assert!(p.iter_with_arclen().eq([
+                (Point { x: 0, y: 0 }, 0.0),
+                (Point { x: 3, y: 4 }, 5.0),
+                (Point { x: 3, y: 4 }, 5.0),
+                (Point { x: 3, y: 6 }, 7.0),
+            ]));
+        }
+
+        #[test]
+        fn spans_from_zero_to_total_length() {
+            let p: Polyline = (0..50)
+                .map(|i| Point {
+                    x: i * 7 % 13 - 6,
+                    y: i * i % 17,
+                })
+                .collect();
+
+            let arc_lengths: Vec<_> = p.iter_with_arclen().collect();
+
+            assert_eq!(arc_lengths.len(), p.len());
+            assert_eq!(arc_lengths[0], (p.start(), 0.0));
+            assert_eq!(arc_lengths.last().unwrap().1, p.total_length());
+        }
+
+        #[test]
+        fn yields_zero_for_single_vertex() {
+            let p = Polyline::new(Point { x: 2, y: -2 });
+
+            assert!(p.iter_with_arclen().eq([(Point { x: 2, y: -2 }, 0.0)]));
+        }
+    }
+
+    mod arc_length_table {
+        use super::*;
+
+        #[test]
+        fn sums_segment_lengths() {
+            let p = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![
+                    Point { x: 3, y: 4 },
+                    Point { x: 3, y: 4 },
+                    Point { x: 3, y: 6 },
+                ],
+            );
+
+            assert_eq!(p.arc_length_table(), [0.0, 5.0, 5.0, 7.0]);
+            assert_eq!(Polyline::new(Point::default()).arc_length_table(), [0.0]);
+        }
+
+        #[test]
+        fn matches_naive_point_at() {
+            let p: Polyline = (0..50)
+                .map(|i| Point {
+                    x: i * 7 % 13 - 6,
+                    y: i * i % 17,
+                })
+                .collect();
+            let table = p.arc_length_table();
+            let total = p.total_length();
+
+            for i in -10..1000 {
+                let arc_length = total * f64::from(i) / 990.0;
+
+                assert_eq!(p.point_at_with(arc_length, &table), p.point_at(arc_length));
+            }
+        }
+    }
+
+    mod resample {
+        use super::*;
+
+        #[test]
+        fn spaces_straight_segment_evenly() {
+            let p = Polyline::with_points(Point { x: 0, y: 0 }, vec![Point { x: 10, y: 0 }]);
+
+            assert_eq!(
+                p.resample(11),
+                (0..=10).map(|x| Point { x, y: 0 }).collect()
+            );
+        }
+
+        #[test]
+        fn follows_path_across_vertices() {
+            let p = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![Point { x: 4, y: 0 }, Point { x: 4, y: 4 }],
+            );
+
+            assert_eq!(
+                p.resample(5),
+                Polyline::with_points(
+                    Point { x: 0, y: 0 },
+                    vec![
+                        Point { x: 2, y: 0 },
+                        Point { x: 4, y: 0 },
+                        Point { x: 4, y: 2 },
+                        Point { x: 4, y: 4 },
+                    ],
+                ),
+            );
+        }
+
+        #[test]
+        fn preserves_endpoints() {
+            let p: Polyline = (0..7).map(|i| Point { x: i * 3, y: i * i }).collect();
+
+            for count in 2..20 {
+                let resampled = p.resample(count);
+
+                assert_eq!(resampled.len(), count);
+                assert_eq!(resampled.start(), p.start());
+                assert_eq!(resampled.iter().last(), p.iter().last());
+            }
+        }
+
+        #[test]
+        fn keeps_start_for_single_vertex() {
+            let p = Polyline::with_points(Point { x: 1, y: 1 }, vec![Point { x: 5, y: 1 }]);
+
+            assert_eq!(p.resample(1), Polyline::new(Point { x: 1, y: 1 }));
+        }
+
+        #[test]
+        fn repeats_vertex_of_degenerate_polyline() {
+            let p = Polyline::new(Point { x: 1, y: 1 });
+
+            assert_eq!(
+                p.resample(3),
+                Polyline::with_points(p.start(), vec![p.start(); 2])
+            );
+        }
+
+        #[test]
+        #[should_panic = "cannot resample Polyline into zero vertices"]
+        fn panics_on_zero_count() {
+            let _ = Polyline::new(Point::default()).resample(0);
+        }
+
+        #[test]
+        fn try_resample_errors_on_zero_count() {
+            let p = Polyline::new(Point::default());
+
+            assert_eq!(p.try_resample(0), Err(PolylineError::InvalidCount));
+            assert_eq!(p.try_resample(1), Ok(p.clone()));
+        }
+    }
+
+    mod to_json {
+        use super::*;
+
+        #[test]
+        fn renders_canonical_object() {
+            let p = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![Point { x: 1, y: 2 }, Point { x: -3, y: 4 }],
+            );
+
+            assert_eq!(
+                p.to_json(),
+                r#"{"start":{"x":0,"y":0},"points":[{"x":1,"y":2},{"x":-3,"y":4}]}"#,
+            );
+        }
+
+        #[test]
+        fn renders_empty_points_array() {
+            let p = Polyline::new(Point { x: 5, y: 6 });
+
+            assert_eq!(p.to_json(), r#"{"start":{"x":5,"y":6},"points":[]}"#);
+        }
+    }
+
+    mod to_svg_path {
+        use super::*;
+
+        fn polyline() -> Polyline {
+            Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![Point { x: 10, y: 20 }, Point { x: -3, y: 4 }],
+            )
+        }
+
+        #[test]
+        fn renders_open_path() {
+            assert_eq!(polyline().to_svg_path(), "M 0 0 L 10 20 L -3 4");
+        }
+
+        #[test]
+        fn renders_closed_path() {
+            assert_eq!(polyline().to_closed_svg_path(), "M 0 0 L 10 20 L -3 4 Z");
+        }
+
+        #[test]
+        fn renders_single_vertex_as_move() {
+            let p = Polyline::new(Point { x: 5, y: 6 });
+
+            assert_eq!(p.to_svg_path(), "M 5 6");
+        }
+    }
+
+    mod try_from {
+        use super::*;
+
+        #[test]
+        fn converts_vertices_in_order() {
+            let p = Polyline::try_from(vec![Point { x: 1, y: 2 }, Point { x: 3, y: 4 }]);
+
+            assert_eq!(
+                p,
+                Ok(Polyline::with_points(
+                    Point { x: 1, y: 2 },
+                    vec![Point { x: 3, y: 4 }]
+                )),
+            );
+        }
+
+        #[test]
+        fn flattens_start_first() {
+            let p = Polyline::with_points(Point { x: 1, y: 2 }, vec![Point { x: 3, y: 4 }]);
+
+            assert_eq!(Vec::from(p), [Point { x: 1, y: 2 }, Point { x: 3, y: 4 }]);
+        }
+
+        #[test]
+        fn round_trips_preserving_order() {
+            let vertices: Vec<_> = (0..5).map(|i| Point { x: i, y: -i }).collect();
+
+            let p = Polyline::try_from(vertices.clone()).unwrap();
+
+            assert_eq!(Vec::from(p), vertices);
+        }
+
+        #[test]
+        fn errors_on_empty_vec() {
+            assert_eq!(Polyline::try_from(vec![]), Err(PolylineError::Empty));
+        }
+    }
+
+    mod fold_vertices {
+        use super::*;
+
+        #[test]
+        fn folds_start_first() {
+            let p = Polyline::with_points(
+                Point { x: 1, y: 0 },
+                vec![Point { x: 2, y: 0 }, Point { x: -4, y: 0 }],
+            );
+
+            assert_eq!(p.fold_vertices(0, |sum, p| sum + p.x), -1);
+            assert_eq!(
+                p.fold_vertices(vec![], |mut xs, p| {
+                    xs.push(p.x);
+                    xs
+                }),
+                [1, 2, -4],
+            );
+        }
+    }
+
+    mod segments {
+        use super::*;
+
+        #[test]
+        fn yields_none_for_single_vertex() {
+            assert_eq!(Polyline::new(Point::default()).segments().count(), 0);
+        }
+
+        #[test]
+        fn yields_consecutive_pairs() {
+            let (a, b, c) = (
+                Point { x: 0, y: 0 },
+                Point { x: 1, y: 2 },
+                Point { x: 3, y: 4 },
+            );
+            let p = Polyline::with_points(a, vec![b, c]);
+
+            let segments: Vec<_> = p.segments().collect();
+
+            assert_eq!(segments.len(), p.len() - 1);
+            assert_eq!(segments.first(), Some(&(a, b)));
+            assert_eq!(segments.last(), Some(&(b, c)));
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{Point, Polyline, polyline::PolylineError};
+
+/// Builder of a [`Polyline`], validating its non-empty invariant on
+/// [`PolylineBuilder::build()`].
+#[derive(Clone, Debug, Default)]
+pub struct PolylineBuilder {
+    start: Option<Point>,
+    points: Vec<Point>,
+}
+
+impl PolylineBuilder {
+    /// Sets the `start` vertex of the [`Polyline`] being built.
+    #[must_use]
+    pub const fn start(mut self, start: Point) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Appends the `p` vertex after the `start` one and all the previously
+    /// added.
+    #[must_use]
+    pub fn point(mut self, p: Point) -> Self {
+        self.points.push(p);
+        self
+    }
+
+    /// Builds the [`Polyline`].
+    ///
+    /// # Errors
+    ///
+    /// [`PolylineError::Empty`] if the `start` vertex was never set.
+    pub fn build(self) -> Result<Polyline, PolylineError> {
+        let start = self.start.ok_or(PolylineError::Empty)?;
+        Ok(Polyline::with_points(start, self.points))
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use super::*;
+
+    mod build {
+        use super::*;
+
+        #[test]
+        fn builds_polyline_in_order() {
+            let p = PolylineBuilder::default()
+                .start(Point { x: 0, y: 0 })
+                .point(Point { x: 1, y: 1 })
+                .point(Point { x: 2, y: 0 })
+                .build();
+
+            assert_eq!(
+                p,
+                Ok(Polyline::with_points(
+                    Point { x: 0, y: 0 },
+                    vec![Point { x: 1, y: 1 }, Point { x: 2, y: 0 }],
+                )),
+            );
+        }
+
+        #[test]
+        fn allows_setting_start_after_points() {
+            let p = PolylineBuilder::default()
+                .point(Point { x: 1, y: 1 })
+                .start(Point { x: 0, y: 0 })
+                .build();
+
+            assert_eq!(
+                p,
+                Ok(Polyline::with_points(
+                    Point { x: 0, y: 0 },
+                    vec![Point { x: 1, y: 1 }]
+                )),
+            );
+        }
+
+        #[test]
+        fn errors_without_start() {
+            assert_eq!(
+                PolylineBuilder::default().build(),
+                Err(PolylineError::Empty)
+            );
+            assert_eq!(
+                PolylineBuilder::default().point(Point::default()).build(),
+                Err(PolylineError::Empty),
+            );
+        }
+    }
+}
@@ -0,0 +1,207 @@
+use crate::{Point, Polyline};
+
+/// Circle with a floating point `center` and `radius`.
+#[derive(Clone, Copy, Debug)]
+struct Circle {
+    center: (f64, f64),
+    radius: f64,
+}
+
+impl Circle {
+    /// Circle of zero radius at the provided `p` point.
+    fn at(p: (f64, f64)) -> Self {
+        Self {
+            center: p,
+            radius: 0.0,
+        }
+    }
+
+    /// Smallest circle passing through both the `a` and `b` points.
+    fn diameter(a: (f64, f64), b: (f64, f64)) -> Self {
+        Self {
+            center: ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0),
+            radius: distance(a, b) / 2.0,
+        }
+    }
+
+    /// Smallest circle enclosing all the `a`, `b` and `c` points, being either
+    /// the circumscribed one, or the [`Circle::diameter()`] of the farthest
+    /// pair, if they are collinear.
+    fn enclosing(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> Self {
+        let (bx, by) = (b.0 - a.0, b.1 - a.1);
+        let (cx, cy) = (c.0 - a.0, c.1 - a.1);
+        let d = 2.0 * (bx * cy - by * cx);
+        if d == 0.0 {
+            return [
+                Self::diameter(a, b),
+                Self::diameter(a, c),
+                Self::diameter(b, c),
+            ]
+            .into_iter()
+            .max_by(|l, r| l.radius.total_cmp(&r.radius))
+            .unwrap_or(Self::at(a));
+        }
+        let (b2, c2) = (bx * bx + by * by, cx * cx + cy * cy);
+        let (ux, uy) = ((cy * b2 - by * c2) / d, (bx * c2 - cx * b2) / d);
+        Self {
+            center: (a.0 + ux, a.1 + uy),
+            radius: ux.hypot(uy),
+        }
+    }
+
+    /// Checks whether the `p` point lies within this [`Circle`], tolerating
+    /// floating point rounding errors.
+    fn contains(&self, p: (f64, f64)) -> bool {
+        distance(self.center, p) <= self.radius * (1.0 + 1e-12) + 1e-9
+    }
+}
+
+/// Euclidean distance between the `a` and `b` points.
+fn distance(a: (f64, f64), b: (f64, f64)) -> f64 {
+    (b.0 - a.0).hypot(b.1 - a.1)
+}
+
+impl Polyline {
+    /// Computes the minimum enclosing circle of this [`Polyline`]'s vertices
+    /// with the [Welzl's algorithm][1], returning its center and radius.
+    ///
+    /// The center is rounded to the nearest integer [`Point`], and the radius
+    /// is then measured from the rounded center to the farthest vertex, so it
+    /// always encloses every vertex, while exceeding the exact minimal one by
+    /// at most `√2 / 2`.
+    ///
+    /// Vertices are processed in their `start`-first order without shuffling,
+    /// making the result deterministic, but `O(n³)` in the worst case.
+    ///
+    /// [1]: https://en.wikipedia.org/wiki/Smallest-circle_problem#Welzl's_algorithm
+    #[must_use]
+    pub fn bounding_circle(&self) -> (Point, f64) {
+        let points: Vec<_> = self
+            .iter()
+            .map(|p| (f64::from(p.x), f64::from(p.y)))
+            .collect();
+
+        let mut circle = Circle::at(points[0]);
+        for (i, &a) in points.iter().enumerate() {
+            if circle.contains(a) {
+                continue;
+            }
+            circle = Circle::at(a);
+            for (j, &b) in points[..i].iter().enumerate() {
+                if circle.contains(b) {
+                    continue;
+                }
+                circle = Circle::diameter(a, b);
+                for &c in &points[..j] {
+                    if !circle.contains(c) {
+                        circle = Circle::enclosing(a, b, c);
+                    }
+                }
+            }
+        }
+
+        let center = Point {
+            x: circle.center.0.round() as i32,
+            y: circle.center.1.round() as i32,
+        };
+        let radius = self
+            .iter()
+            .map(|p| center.distance_to(*p))
+            .fold(0.0, f64::max);
+        (center, radius)
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use super::*;
+
+    mod bounding_circle {
+        use super::*;
+
+        fn assert_encloses(p: &Polyline) -> (Point, f64) {
+            let (center, radius) = p.bounding_circle();
+            for v in p.iter() {
+                assert!(
+                    v.distance_to(center) <= radius + 1e-9,
+                    "{v} lies outside of circle at {center} of {radius} radius",
+                );
+            }
+            (center, radius)
+        }
+
+        #[test]
+        fn encloses_square() {
+            let p: Polyline = [(0, 0), (4, 0), (4, 4), (0, 4)]
+                .into_iter()
+                .map(Point::from)
+                .collect();
+
+            let (center, radius) = assert_encloses(&p);
+
+            assert_eq!(center, Point { x: 2, y: 2 });
+            assert!((radius - 8_f64.sqrt()).abs() < 1e-9);
+        }
+
+        #[test]
+        fn encloses_triangle_by_circumcircle() {
+            let p: Polyline = [(0, 0), (6, 0), (3, 4)]
+                .into_iter()
+                .map(Point::from)
+                .collect();
+
+            let (center, radius) = assert_encloses(&p);
+
+            // Circumcenter is at `(3, 0.875)`, with `3.125` radius.
+            assert_eq!(center, Point { x: 3, y: 1 });
+            assert!((radius - 10_f64.sqrt()).abs() < 1e-9);
+        }
+
+        #[test]
+        fn encloses_obtuse_triangle_by_diameter() {
+            let p: Polyline = [(0, 0), (10, 0), (5, 1)]
+                .into_iter()
+                .map(Point::from)
+                .collect();
+
+            let (center, radius) = assert_encloses(&p);
+
+            assert_eq!(center, Point { x: 5, y: 0 });
+            assert!((radius - 5.0).abs() < 1e-9);
+        }
+
+        #[test]
+        fn encloses_collinear_vertices() {
+            let p: Polyline = [(3, 3), (-1, -1), (1, 1), (7, 7)]
+                .into_iter()
+                .map(Point::from)
+                .collect();
+
+            let (center, _) = assert_encloses(&p);
+
+            assert_eq!(center, Point { x: 3, y: 3 });
+        }
+
+        #[test]
+        fn encloses_scattered_vertices() {
+            let p: Polyline = (0..50)
+                .map(|i| Point {
+                    x: (i * 37) % 101 - 50,
+                    y: (i * 53) % 89 - 44,
+                })
+                .collect();
+
+            let (_, radius) = assert_encloses(&p);
+
+            let (min, max) = p.bounding_box();
+            assert!(radius <= min.distance_to(max) / 2.0 + 1.0);
+        }
+
+        #[test]
+        fn shrinks_to_single_vertex() {
+            let p = Polyline::new(Point { x: -3, y: 8 });
+
+            assert_eq!(p.bounding_circle(), (Point { x: -3, y: 8 }, 0.0));
+        }
+    }
+}
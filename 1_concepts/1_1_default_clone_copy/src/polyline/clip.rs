@@ -0,0 +1,190 @@
+use crate::{Point, Polyline};
+
+impl Polyline {
+    /// Clips this [`Polyline`] to the axis-aligned box between the `min` and
+    /// `max` corners (inclusive), with the [Liang–Barsky][1] algorithm.
+    ///
+    /// Intersections with the box edges are computed in [`f64`] and rounded to
+    /// the nearest integer coordinates with [`Point::lerp`], so they never
+    /// leave the box. If this [`Polyline`] leaves the box and enters it again,
+    /// the exit and re-entry points are joined directly, which keeps the
+    /// result connected and still inside the box.
+    ///
+    /// This deliberately doesn't use the [`segment_intersection()`] with each
+    /// of the box edges: Liang–Barsky finds both the entry and the exit of a
+    /// segment in a single pass over the 4 edges, instead of searching each of
+    /// them for a crossing point.
+    ///
+    /// Returns [`None`] if no part of this [`Polyline`] lies inside the box.
+    ///
+    /// Expects `min.x <= max.x` and `min.y <= max.y`, which is checked in
+    /// debug builds only.
+    ///
+    /// [1]: https://en.wikipedia.org/wiki/Liang%E2%80%93Barsky_algorithm
+    /// [`segment_intersection()`]: super::segment_intersection
+    #[must_use]
+    pub fn clip_to_box(&self, min: Point, max: Point) -> Option<Self> {
+        debug_assert!(
+            min.x <= max.x && min.y <= max.y,
+            "`min` must not exceed `max` on any axis",
+        );
+
+        if self.points.is_empty() {
+            return (self.start.clamp(min, max) == self.start).then(|| self.clone());
+        }
+
+        let mut clipped: Option<Self> = None;
+        for (a, b) in self.segments() {
+            let Some((t0, t1)) = clip_segment(a, b, min, max) else {
+                continue;
+            };
+            for p in [a.lerp(b, t0), a.lerp(b, t1)] {
+                match &mut clipped {
+                    None => clipped = Some(Self::new(p)),
+                    Some(c) if *c.points.last().unwrap_or(&c.start) != p => c.push(p),
+                    Some(_) => {}
+                }
+            }
+        }
+        clipped
+    }
+}
+
+/// Returns the `(t0, t1)` parameters of the `a`-`b` segment part lying inside
+/// the box between the `min` and `max` corners, if any.
+fn clip_segment(a: Point, b: Point, min: Point, max: Point) -> Option<(f64, f64)> {
+    let dx = f64::from(b.x) - f64::from(a.x);
+    let dy = f64::from(b.y) - f64::from(a.y);
+    let edges = [
+        (-dx, f64::from(a.x) - f64::from(min.x)),
+        (dx, f64::from(max.x) - f64::from(a.x)),
+        (-dy, f64::from(a.y) - f64::from(min.y)),
+        (dy, f64::from(max.y) - f64::from(a.y)),
+    ];
+
+    let (mut t0, mut t1) = (0.0_f64, 1.0_f64);
+    for (p, q) in edges {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else if p < 0.0 {
+            t0 = t0.max(q / p);
+        } else {
+            t1 = t1.min(q / p);
+        }
+        if t0 > t1 {
+            return None;
+        }
+    }
+    Some((t0, t1))
+}
+
+#[cfg(test)]
+mod spec {
+    use super::*;
+
+    mod clip_to_box {
+        use super::*;
+
+        const MIN: Point = Point { x: -5, y: -5 };
+        const MAX: Point = Point { x: 5, y: 5 };
+
+        #[test]
+        fn keeps_fully_inside() {
+            let p = Polyline::with_points(
+                Point { x: -5, y: 0 },
+                vec![Point { x: 0, y: 3 }, Point { x: 5, y: 5 }],
+            );
+
+            assert_eq!(p.clip_to_box(MIN, MAX), Some(p));
+        }
+
+        #[test]
+        fn drops_fully_outside() {
+            let p = Polyline::with_points(
+                Point { x: -10, y: 6 },
+                vec![Point { x: 10, y: 6 }, Point { x: 10, y: -10 }],
+            );
+
+            assert_eq!(p.clip_to_box(MIN, MAX), None);
+            assert_eq!(
+                Polyline::new(Point { x: 6, y: 0 }).clip_to_box(MIN, MAX),
+                None
+            );
+        }
+
+        #[test]
+        fn keeps_single_vertex_inside() {
+            let p = Polyline::new(Point { x: 5, y: -5 });
+
+            assert_eq!(p.clip_to_box(MIN, MAX), Some(p));
+        }
+
+        #[test]
+        fn cuts_at_straddled_edge() {
+            let p = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![Point { x: 2, y: 2 }, Point { x: 12, y: 2 }],
+            );
+
+            assert_eq!(
+                p.clip_to_box(MIN, MAX),
+                Some(Polyline::with_points(
+                    Point { x: 0, y: 0 },
+                    vec![Point { x: 2, y: 2 }, Point { x: 5, y: 2 }],
+                )),
+            );
+        }
+
+        #[test]
+        fn rounds_diagonal_intersections() {
+            let p = Polyline::with_points(Point { x: 0, y: 0 }, vec![Point { x: 10, y: 3 }]);
+
+            assert_eq!(
+                p.clip_to_box(MIN, MAX),
+                Some(Polyline::with_points(
+                    Point { x: 0, y: 0 },
+                    vec![Point { x: 5, y: 2 }]
+                )),
+            );
+        }
+
+        #[test]
+        fn crosses_box_entirely() {
+            let p = Polyline::with_points(Point { x: -10, y: 1 }, vec![Point { x: 10, y: 1 }]);
+
+            assert_eq!(
+                p.clip_to_box(MIN, MAX),
+                Some(Polyline::with_points(
+                    Point { x: -5, y: 1 },
+                    vec![Point { x: 5, y: 1 }]
+                )),
+            );
+        }
+
+        #[test]
+        fn joins_reentering_parts() {
+            let p = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![
+                    Point { x: 10, y: 0 },
+                    Point { x: 10, y: 2 },
+                    Point { x: 0, y: 2 },
+                ],
+            );
+
+            assert_eq!(
+                p.clip_to_box(MIN, MAX),
+                Some(Polyline::with_points(
+                    Point { x: 0, y: 0 },
+                    vec![
+                        Point { x: 5, y: 0 },
+                        Point { x: 5, y: 2 },
+                        Point { x: 0, y: 2 },
+                    ],
+                )),
+            );
+        }
+    }
+}
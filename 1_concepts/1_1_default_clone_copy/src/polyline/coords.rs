@@ -0,0 +1,100 @@
+use crate::{Point, PointParseError, Polyline, point::parse_coordinate};
+
+impl Polyline {
+    /// Parses a [`Polyline`] from a flat stream of whitespace-separated
+    /// integer coordinates, like `"0 0 10 20\n-3 4"`, read two at a time into
+    /// `x` and `y` of each vertex, `start` first.
+    ///
+    /// Tokens are consumed lazily, so no intermediate collection of them is
+    /// built for large inputs.
+    ///
+    /// # Errors
+    ///
+    /// - [`PointParseError::NotANumber`] if a token is not an integer.
+    /// - [`PointParseError::OutOfRange`] if a token doesn't fit into [`i32`].
+    /// - [`PointParseError::MissingComponent`] if the last `x` has no `y`
+    ///   pair.
+    /// - [`PointParseError::Empty`] if the `input` has no coordinates at all.
+    pub fn parse_coords(input: &str) -> Result<Self, PointParseError> {
+        let mut coords = input.split_whitespace().map(parse_coordinate);
+        let start = match coords.next() {
+            Some(x) => Point {
+                x: x?,
+                y: coords.next().ok_or(PointParseError::MissingComponent)??,
+            },
+            None => return Err(PointParseError::Empty),
+        };
+        let mut polyline = Self::new(start);
+        while let Some(x) = coords.next() {
+            let x = x?;
+            let y = coords.next().ok_or(PointParseError::MissingComponent)??;
+            polyline.push(Point { x, y });
+        }
+        Ok(polyline)
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use super::*;
+
+    mod parse_coords {
+        use super::*;
+
+        #[test]
+        fn parses_pairs_across_lines() {
+            assert_eq!(
+                Polyline::parse_coords("0 0  10 20\n-3\t4\n"),
+                Ok(Polyline::with_points(
+                    Point { x: 0, y: 0 },
+                    vec![Point { x: 10, y: 20 }, Point { x: -3, y: 4 }],
+                )),
+            );
+        }
+
+        #[test]
+        fn parses_single_pair() {
+            assert_eq!(
+                Polyline::parse_coords(" 7 -8 "),
+                Ok(Polyline::new(Point { x: 7, y: -8 })),
+            );
+        }
+
+        #[test]
+        fn errors_on_odd_count() {
+            for input in ["0", "0 0 10"] {
+                assert_eq!(
+                    Polyline::parse_coords(input),
+                    Err(PointParseError::MissingComponent),
+                    "for {input:?}",
+                );
+            }
+        }
+
+        #[test]
+        fn errors_on_non_numeric_token() {
+            for (input, token) in [("0 0 1 x", "x"), ("0 0 y 1", "y"), ("1.5 2", "1.5")] {
+                assert_eq!(
+                    Polyline::parse_coords(input),
+                    Err(PointParseError::NotANumber(token.into())),
+                    "for {input:?}",
+                );
+            }
+        }
+
+        #[test]
+        fn errors_on_out_of_range_token() {
+            assert_eq!(
+                Polyline::parse_coords("0 0 9999999999 1"),
+                Err(PointParseError::OutOfRange),
+            );
+        }
+
+        #[test]
+        fn errors_on_empty_input() {
+            for input in ["", "  \n\t "] {
+                assert_eq!(Polyline::parse_coords(input), Err(PointParseError::Empty));
+            }
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use std::{error::Error, fmt};
+
+/// Error of a fallible [`Polyline`] operation.
+///
+/// [`Polyline`]: super::Polyline
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PolylineError {
+    /// Resulting [`Polyline`] would have no vertices at all.
+    ///
+    /// [`Polyline`]: super::Polyline
+    Empty,
+
+    /// Requested number of vertices cannot be produced.
+    InvalidCount,
+
+    /// Provided tolerance is negative or NaN.
+    InvalidEpsilon,
+
+    /// Parsed input is not a GeoJSON `LineString` geometry of [`i32`]
+    /// coordinates.
+    InvalidGeoJson,
+}
+
+impl fmt::Display for PolylineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Empty => "polyline must have at least one vertex",
+            Self::InvalidCount => "vertex count must be positive",
+            Self::InvalidEpsilon => "epsilon must be a non-negative number",
+            Self::InvalidGeoJson => "input must be a GeoJSON `LineString` geometry",
+        })
+    }
+}
+
+impl Error for PolylineError {}
+
+#[cfg(test)]
+mod spec {
+    use super::*;
+
+    mod display {
+        use super::*;
+
+        #[test]
+        fn describes_each_variant() {
+            assert_eq!(
+                PolylineError::Empty.to_string(),
+                "polyline must have at least one vertex",
+            );
+            assert_eq!(
+                PolylineError::InvalidCount.to_string(),
+                "vertex count must be positive",
+            );
+            assert_eq!(
+                PolylineError::InvalidEpsilon.to_string(),
+                "epsilon must be a non-negative number",
+            );
+            assert_eq!(
+                PolylineError::InvalidGeoJson.to_string(),
+                "input must be a GeoJSON `LineString` geometry",
+            );
+        }
+
+        #[test]
+        fn is_std_error() {
+            let err: Box<dyn Error> = Box::new(PolylineError::InvalidCount);
+
+            assert!(err.source().is_none());
+            assert_eq!(err.to_string(), "vertex count must be positive");
+        }
+    }
+}
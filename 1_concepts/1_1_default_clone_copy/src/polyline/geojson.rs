@@ -0,0 +1,358 @@
+use std::{iter::Peekable, str::Chars};
+
+use crate::{Point, Polyline, polyline::PolylineError};
+
+impl Polyline {
+    /// Exports this [`Polyline`] as a GeoJSON [`LineString`][1] geometry,
+    /// like `{"type":"LineString","coordinates":[[0,0],[1,2]]}`, without
+    /// pulling any dependencies.
+    ///
+    /// [1]: https://datatracker.ietf.org/doc/html/rfc7946#section-3.1.4
+    #[must_use]
+    pub fn to_geojson(&self) -> String {
+        let coordinates: Vec<_> = self.iter().map(|p| format!("[{},{}]", p.x, p.y)).collect();
+        format!(
+            r#"{{"type":"LineString","coordinates":[{}]}}"#,
+            coordinates.join(","),
+        )
+    }
+
+    /// Parses a [`Polyline`] from a GeoJSON [`LineString`][1] geometry.
+    ///
+    /// Members other than `type` and `coordinates` are ignored, as well as
+    /// any positions' elements after the `x` and `y` ones (like altitude).
+    /// Fractional coordinates are rounded to the nearest integers, with
+    /// halves rounded away from zero.
+    ///
+    /// # Errors
+    ///
+    /// - [`PolylineError::InvalidGeoJson`] if the `input` is not a valid JSON
+    ///   object of a `LineString` geometry, nests arrays and objects deeper
+    ///   than 32 levels, or any of its coordinates doesn't fit into [`i32`]
+    ///   once rounded.
+    /// - [`PolylineError::Empty`] if the geometry has no coordinates at all.
+    ///
+    /// [1]: https://datatracker.ietf.org/doc/html/rfc7946#section-3.1.4
+    pub fn from_geojson(input: &str) -> Result<Self, PolylineError> {
+        let Json::Object(members) = Json::parse(input)? else {
+            return Err(PolylineError::InvalidGeoJson);
+        };
+        let member = |key: &str| {
+            members
+                .iter()
+                .find_map(|(k, v)| (k == key).then_some(v))
+                .ok_or(PolylineError::InvalidGeoJson)
+        };
+        if member("type")? != &Json::String("LineString".into()) {
+            return Err(PolylineError::InvalidGeoJson);
+        }
+        let Json::Array(positions) = member("coordinates")? else {
+            return Err(PolylineError::InvalidGeoJson);
+        };
+        positions
+            .iter()
+            .map(|position| match position {
+                Json::Array(coords) => match coords.as_slice() {
+                    [Json::Number(x), Json::Number(y), ..] => Ok(Point {
+                        x: coordinate(*x)?,
+                        y: coordinate(*y)?,
+                    }),
+                    _ => Err(PolylineError::InvalidGeoJson),
+                },
+                _ => Err(PolylineError::InvalidGeoJson),
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .try_into()
+    }
+}
+
+/// Rounds the provided GeoJSON coordinate into an [`i32`] one.
+fn coordinate(c: f64) -> Result<i32, PolylineError> {
+    let c = c.round();
+    if (f64::from(i32::MIN)..=f64::from(i32::MAX)).contains(&c) {
+        Ok(c as i32)
+    } else {
+        Err(PolylineError::InvalidGeoJson)
+    }
+}
+
+/// Maximum nesting depth of JSON arrays and objects accepted while reading
+/// GeoJSON, so malicious input can't overflow the stack of the recursive
+/// parser.
+///
+/// A `LineString` geometry only needs 3 levels, leaving plenty for any nested
+/// foreign members.
+const MAX_DEPTH: usize = 32;
+
+/// Minimal JSON value, as much as needed to read GeoJSON geometries.
+#[derive(Debug, PartialEq)]
+enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    /// Parses the whole `input` as a single JSON value.
+    fn parse(input: &str) -> Result<Self, PolylineError> {
+        let mut chars = input.chars().peekable();
+        let value = Self::parse_value(&mut chars, 0)?;
+        skip_whitespace(&mut chars);
+        match chars.next() {
+            None => Ok(value),
+            Some(_) => Err(PolylineError::InvalidGeoJson),
+        }
+    }
+
+    /// Parses a single JSON value from the provided `chars`, skipping any
+    /// leading whitespace, being nested into `depth` arrays and objects.
+    fn parse_value(chars: &mut Peekable<Chars<'_>>, depth: usize) -> Result<Self, PolylineError> {
+        skip_whitespace(chars);
+        match chars.peek().ok_or(PolylineError::InvalidGeoJson)? {
+            '{' | '[' if depth >= MAX_DEPTH => Err(PolylineError::InvalidGeoJson),
+            '{' => {
+                chars.next();
+                let members = parse_sequence(chars, '}', |chars| {
+                    skip_whitespace(chars);
+                    let key = parse_string(chars)?;
+                    skip_whitespace(chars);
+                    expect(chars, ':')?;
+                    Ok((key, Self::parse_value(chars, depth + 1)?))
+                })?;
+                Ok(Self::Object(members))
+            }
+            '[' => {
+                chars.next();
+                let items =
+                    parse_sequence(chars, ']', |chars| Self::parse_value(chars, depth + 1))?;
+                Ok(Self::Array(items))
+            }
+            '"' => parse_string(chars).map(Self::String),
+            '-' | '0'..='9' => {
+                let mut number = String::new();
+                while let Some(c) =
+                    chars.next_if(|c| matches!(c, '-' | '+' | '.' | 'e' | 'E' | '0'..='9'))
+                {
+                    number.push(c);
+                }
+                number
+                    .parse()
+                    .map(Self::Number)
+                    .map_err(|_| PolylineError::InvalidGeoJson)
+            }
+            _ => {
+                let mut word = String::new();
+                while let Some(c) = chars.next_if(char::is_ascii_alphabetic) {
+                    word.push(c);
+                }
+                match word.as_str() {
+                    "null" => Ok(Self::Null),
+                    "true" => Ok(Self::Bool(true)),
+                    "false" => Ok(Self::Bool(false)),
+                    _ => Err(PolylineError::InvalidGeoJson),
+                }
+            }
+        }
+    }
+}
+
+/// Parses comma-separated `item`s until the `close` delimiter, the opening
+/// one being already consumed.
+fn parse_sequence<T>(
+    chars: &mut Peekable<Chars<'_>>,
+    close: char,
+    mut item: impl FnMut(&mut Peekable<Chars<'_>>) -> Result<T, PolylineError>,
+) -> Result<Vec<T>, PolylineError> {
+    let mut items = vec![];
+    skip_whitespace(chars);
+    if chars.next_if_eq(&close).is_some() {
+        return Ok(items);
+    }
+    loop {
+        items.push(item(chars)?);
+        skip_whitespace(chars);
+        match chars.next() {
+            Some(',') => {}
+            Some(c) if c == close => return Ok(items),
+            _ => return Err(PolylineError::InvalidGeoJson),
+        }
+    }
+}
+
+/// Parses a JSON string literal, including its quotes.
+fn parse_string(chars: &mut Peekable<Chars<'_>>) -> Result<String, PolylineError> {
+    expect(chars, '"')?;
+    let mut s = String::new();
+    loop {
+        match chars.next().ok_or(PolylineError::InvalidGeoJson)? {
+            '"' => return Ok(s),
+            '\\' => s.push(match chars.next().ok_or(PolylineError::InvalidGeoJson)? {
+                c @ ('"' | '\\' | '/') => c,
+                'b' => '\u{8}',
+                'f' => '\u{c}',
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                'u' => {
+                    let hex: String = chars.by_ref().take(4).collect();
+                    u32::from_str_radix(&hex, 16)
+                        .ok()
+                        .filter(|_| hex.len() == 4)
+                        .and_then(char::from_u32)
+                        .unwrap_or(char::REPLACEMENT_CHARACTER)
+                }
+                _ => return Err(PolylineError::InvalidGeoJson),
+            }),
+            c => s.push(c),
+        }
+    }
+}
+
+/// Consumes the `expected` char, erroring if there is another one.
+fn expect(chars: &mut Peekable<Chars<'_>>, expected: char) -> Result<(), PolylineError> {
+    chars
+        .next_if_eq(&expected)
+        .map(drop)
+        .ok_or(PolylineError::InvalidGeoJson)
+}
+
+/// Skips any leading JSON whitespace.
+fn skip_whitespace(chars: &mut Peekable<Chars<'_>>) {
+    while chars
+        .next_if(|c| matches!(c, ' ' | '\t' | '\n' | '\r'))
+        .is_some()
+    {}
+}
+
+#[cfg(test)]
+mod spec {
+    use super::*;
+
+    fn polyline() -> Polyline {
+        Polyline::with_points(
+            Point { x: 0, y: 0 },
+            vec![Point { x: 10, y: -20 }, Point { x: -3, y: 4 }],
+        )
+    }
+
+    mod to_geojson {
+        use super::*;
+
+        #[test]
+        fn renders_line_string() {
+            assert_eq!(
+                polyline().to_geojson(),
+                r#"{"type":"LineString","coordinates":[[0,0],[10,-20],[-3,4]]}"#,
+            );
+        }
+    }
+
+    mod from_geojson {
+        use super::*;
+
+        #[test]
+        fn round_trips() {
+            for p in [polyline(), Polyline::new(Point { x: 5, y: 6 })] {
+                assert_eq!(Polyline::from_geojson(&p.to_geojson()), Ok(p));
+            }
+        }
+
+        #[test]
+        fn ignores_whitespace_and_extra_members() {
+            let input = r#" {
+                "bbox": [0, 0, 10, 10],
+                "coordinates": [ [0, 0.0], [1e1, -2e1, 100], [-3, 4] ],
+                "properties": {"name": "path \"A\"", "visible": true, "extra": null},
+                "type": "LineString"
+            } "#;
+
+            assert_eq!(Polyline::from_geojson(input), Ok(polyline()));
+        }
+
+        #[test]
+        fn rounds_fractional_coordinates() {
+            let input = r#"{"type":"LineString","coordinates":[[0.4,-0.5],[2.5,-1.6]]}"#;
+
+            assert_eq!(
+                Polyline::from_geojson(input),
+                Ok(Polyline::with_points(
+                    Point { x: 0, y: -1 },
+                    vec![Point { x: 3, y: -2 }],
+                )),
+            );
+        }
+
+        #[test]
+        fn errors_on_other_geometry_type() {
+            let input = r#"{"type":"Point","coordinates":[1,2]}"#;
+
+            assert_eq!(
+                Polyline::from_geojson(input),
+                Err(PolylineError::InvalidGeoJson)
+            );
+        }
+
+        #[test]
+        fn errors_on_empty_coordinates() {
+            let input = r#"{"type":"LineString","coordinates":[]}"#;
+
+            assert_eq!(Polyline::from_geojson(input), Err(PolylineError::Empty));
+        }
+
+        #[test]
+        fn accepts_nested_foreign_members() {
+            let nested = "[".repeat(MAX_DEPTH - 2) + &"]".repeat(MAX_DEPTH - 2);
+            let input = format!(
+                r#"{{"type":"LineString","coordinates":[[1,2]],"extra":{{"a":{nested}}}}}"#,
+            );
+
+            assert_eq!(
+                Polyline::from_geojson(&input),
+                Ok(Polyline::new(Point { x: 1, y: 2 })),
+            );
+        }
+
+        #[test]
+        fn errors_on_too_deep_nesting() {
+            for depth in [MAX_DEPTH + 1, 200_000] {
+                let nested = "[".repeat(depth) + &"]".repeat(depth);
+                let input =
+                    format!(r#"{{"type":"LineString","coordinates":[[1,2]],"extra":{nested}}}"#,);
+
+                assert_eq!(
+                    Polyline::from_geojson(&nested),
+                    Err(PolylineError::InvalidGeoJson)
+                );
+                assert_eq!(
+                    Polyline::from_geojson(&input),
+                    Err(PolylineError::InvalidGeoJson)
+                );
+            }
+        }
+
+        #[test]
+        fn errors_on_malformed_input() {
+            for input in [
+                "",
+                "[]",
+                r#"{"type":"LineString"}"#,
+                r#"{"type":"LineString","coordinates":[[1]]}"#,
+                r#"{"type":"LineString","coordinates":[[1,"2"]]}"#,
+                r#"{"type":"LineString","coordinates":[[1,2],]}"#,
+                r#"{"type":"LineString","coordinates":[[1,2]]} extra"#,
+                r#"{"type":"LineString","coordinates":[[1,3e9]]}"#,
+                r#"{"type":"LineString" "coordinates":[[1,2]]}"#,
+                r#"{"type":"LineString","coordinates":[[1,2]]"#,
+            ] {
+                assert_eq!(
+                    Polyline::from_geojson(input),
+                    Err(PolylineError::InvalidGeoJson),
+                    "for {input:?}",
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use std::collections::{HashMap, VecDeque, hash_map::Entry};
+
+use crate::{Point, Polyline};
+
+impl Polyline {
+    /// Maximum number of lattice points visited by
+    /// [`Polyline::shortest_grid_path()`] before it gives up.
+    pub const GRID_SEARCH_LIMIT: usize = 1 << 20;
+
+    /// Finds the shortest path from the `start` to the `goal` [`Point`] over
+    /// the lattice points satisfying the `passable` predicate, moving between
+    /// [`Point::neighbors4()`] with a breadth-first search.
+    ///
+    /// The path is returned with a vertex per each step, so its `len()` is the
+    /// number of steps plus one, and a single-vertex [`Polyline`] is returned
+    /// if the `start` is the `goal`.
+    ///
+    /// Returns [`None`] if either endpoint is not `passable`, or the `goal` is
+    /// unreachable. To terminate on open grids, the search gives up after
+    /// visiting [`Polyline::GRID_SEARCH_LIMIT`] points, and points on the edges
+    /// of the [`i32`] range are never stepped over.
+    #[must_use]
+    pub fn shortest_grid_path(
+        start: Point,
+        goal: Point,
+        passable: impl Fn(Point) -> bool,
+    ) -> Option<Self> {
+        Self::shortest_grid_path_within(start, goal, passable, Self::GRID_SEARCH_LIMIT)
+    }
+
+    /// [`Polyline::shortest_grid_path()`] giving up after visiting the `limit`
+    /// number of points.
+    fn shortest_grid_path_within(
+        start: Point,
+        goal: Point,
+        passable: impl Fn(Point) -> bool,
+        limit: usize,
+    ) -> Option<Self> {
+        if !passable(start) || !passable(goal) {
+            return None;
+        }
+
+        let mut came_from = HashMap::from([(start, start)]);
+        let mut queue = VecDeque::from([start]);
+        while let Some(p) = queue.pop_front() {
+            if p == goal {
+                let mut path = vec![goal];
+                let mut at = goal;
+                while at != start {
+                    at = came_from[&at];
+                    path.push(at);
+                }
+                return Some(path.into_iter().rev().collect());
+            }
+            if [p.x, p.y].iter().any(|&c| c == i32::MIN || c == i32::MAX) {
+                continue;
+            }
+            for n in p.neighbors4() {
+                if came_from.len() >= limit {
+                    return None;
+                }
+                if let Entry::Vacant(e) = came_from.entry(n)
+                    && passable(n)
+                {
+                    e.insert(p);
+                    queue.push_back(n);
+                }
+            }
+        }
+        None
+    }
+
+    /// Groups the vertices of this [`Polyline`] by their
+    /// [`Point::spatial_bucket()`] with the provided `cell` size, so only the
+    /// same and adjacent buckets need to be searched for close vertices.
+    ///
+    /// Vertices keep their `start`-first order within each bucket, including
+    /// any duplicates.
+    ///
+    /// Expects a positive `cell`, which is checked in debug builds only.
+    #[must_use]
+    pub fn bucketed_vertices(&self, cell: i32) -> HashMap<(i32, i32), Vec<Point>> {
+        let mut buckets = HashMap::<_, Vec<_>>::new();
+        for &p in self.iter() {
+            buckets.entry(p.spatial_bucket(cell)).or_default().push(p);
+        }
+        buckets
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use super::*;
+
+    mod shortest_grid_path {
+        use super::*;
+
+        /// Checks whether the `path` moves by a single orthogonal step at a
+        /// time.
+        fn is_stepwise(path: &Polyline) -> bool {
+            path.segments()
+                .all(|(a, b)| a.manhattan_distance_to(b) == 1)
+        }
+
+        #[test]
+        fn walks_straight_on_open_grid() {
+            let path =
+                Polyline::shortest_grid_path(Point { x: 0, y: 0 }, Point { x: 4, y: 0 }, |_| true);
+
+            assert_eq!(path, Some((0..=4).map(|x| Point { x, y: 0 }).collect()));
+        }
+
+        #[test]
+        fn walks_around_obstacle() {
+            // Wall at `x = 2` spanning `-2..=2`, so the path goes around it.
+            let passable = |p: Point| p.x != 2 || !(-2..=2).contains(&p.y);
+            let (start, goal) = (Point { x: 0, y: 0 }, Point { x: 4, y: 0 });
+
+            let path = Polyline::shortest_grid_path(start, goal, passable).unwrap();
+
+            assert_eq!(path.iter().next(), Some(&start));
+            assert_eq!(path.iter().last(), Some(&goal));
+            assert!(is_stepwise(&path));
+            assert!(path.iter().all(|&p| passable(p)));
+            assert_eq!(path.len(), 4 + 2 * 3 + 1);
+        }
+
+        #[test]
+        fn returns_none_for_unreachable_goal() {
+            // Goal is boxed in by a ring of walls within a bounded area.
+            let goal = Point { x: 5, y: 5 };
+            let passable = |p: Point| {
+                (0..=10).contains(&p.x)
+                    && (0..=10).contains(&p.y)
+                    && (p.squared_distance_to(goal) > 2 || p == goal)
+            };
+
+            assert_eq!(
+                Polyline::shortest_grid_path(Point::ORIGIN, goal, passable),
+                None,
+            );
+        }
+
+        #[test]
+        fn returns_none_for_impassable_endpoints() {
+            let (start, goal) = (Point::ORIGIN, Point { x: 3, y: 0 });
+
+            assert_eq!(
+                Polyline::shortest_grid_path(start, goal, |p| p != start),
+                None
+            );
+            assert_eq!(
+                Polyline::shortest_grid_path(start, goal, |p| p != goal),
+                None
+            );
+        }
+
+        #[test]
+        fn returns_single_vertex_for_same_endpoints() {
+            let p = Point { x: 1, y: 2 };
+
+            assert_eq!(
+                Polyline::shortest_grid_path(p, p, |_| true),
+                Some(Polyline::new(p)),
+            );
+        }
+
+        #[test]
+        fn gives_up_beyond_search_limit() {
+            let goal = Point { x: 100, y: 0 };
+
+            assert_eq!(
+                Polyline::shortest_grid_path_within(Point::ORIGIN, goal, |_| true, 1_000),
+                None
+            );
+            assert!(
+                Polyline::shortest_grid_path_within(Point::ORIGIN, goal, |_| true, 100_000)
+                    .is_some()
+            );
+        }
+    }
+
+    mod bucketed_vertices {
+        use super::*;
+
+        #[test]
+        fn groups_vertices_by_cell() {
+            let p: Polyline = [(1, 1), (50, 50), (8, 3), (-2, 4), (55, 59), (1, 1)]
+                .into_iter()
+                .map(Point::from)
+                .collect();
+
+            let buckets = p.bucketed_vertices(10);
+
+            assert_eq!(buckets.len(), 3);
+            assert_eq!(buckets[&(0, 0)], [(1, 1), (8, 3), (1, 1)].map(Point::from),);
+            assert_eq!(buckets[&(5, 5)], [(50, 50), (55, 59)].map(Point::from));
+            assert_eq!(buckets[&(-1, 0)], [Point { x: -2, y: 4 }]);
+        }
+
+        #[test]
+        fn puts_single_vertex_into_single_bucket() {
+            let buckets = Polyline::new(Point { x: 7, y: -7 }).bucketed_vertices(5);
+
+            assert_eq!(
+                buckets,
+                HashMap::from([((1, -2), vec![Point { x: 7, y: -7 }])]),
+            );
+        }
+    }
+}
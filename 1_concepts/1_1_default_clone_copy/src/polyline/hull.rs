@@ -0,0 +1,130 @@
+use std::cmp::Ordering;
+
+use super::intersect::orientation;
+use crate::{Point, Polyline};
+
+impl Polyline {
+    /// Computes the convex hull of this [`Polyline`]'s vertices with
+    /// [Andrew's monotone chain][1] algorithm.
+    ///
+    /// The hull is returned as a closed counter-clockwise loop (assuming the
+    /// `y` axis points up), starting at the lexicographically smallest vertex.
+    /// Vertices lying on the hull's edges are dropped, so all-collinear input
+    /// degrades to the loop between its two extreme points, and a
+    /// single-vertex [`Polyline`] stays as is.
+    ///
+    /// [1]: https://en.wikibooks.org/wiki/Algorithm_Implementation/Geometry/Convex_hull/Monotone_chain
+    #[must_use]
+    pub fn convex_hull(&self) -> Self {
+        let mut points: Vec<_> = self.iter().copied().collect();
+        points.sort_unstable();
+        points.dedup();
+
+        let mut hull = Vec::with_capacity(points.len() + 1);
+        for &p in &points {
+            push_convex(&mut hull, p, 2);
+        }
+        let lower_len = hull.len();
+        for &p in points.iter().rev().skip(1) {
+            push_convex(&mut hull, p, lower_len + 1);
+        }
+        hull.into_iter().collect()
+    }
+}
+
+/// Pushes the `p` point onto the `hull` chain, popping its last vertices
+/// (while at least `min_len` of them remain) that don't make a strict
+/// counter-clockwise turn towards `p`.
+fn push_convex(hull: &mut Vec<Point>, p: Point, min_len: usize) {
+    while hull.len() >= min_len
+        && orientation(hull[hull.len() - 2], hull[hull.len() - 1], p) != Ordering::Greater
+    {
+        hull.pop();
+    }
+    hull.push(p);
+}
+
+#[cfg(test)]
+mod spec {
+    use super::*;
+
+    mod convex_hull {
+        use super::*;
+
+        #[test]
+        fn drops_interior_vertices() {
+            let p: Polyline = [(2, 2), (0, 0), (4, 4), (1, 3), (4, 0), (0, 4)]
+                .into_iter()
+                .map(Point::from)
+                .collect();
+
+            assert_eq!(
+                p.convex_hull(),
+                [(0, 0), (4, 0), (4, 4), (0, 4), (0, 0)]
+                    .into_iter()
+                    .map(Point::from)
+                    .collect(),
+            );
+        }
+
+        #[test]
+        fn drops_vertices_on_edges() {
+            let p: Polyline = [(0, 0), (2, 0), (4, 0), (4, 4), (0, 4)]
+                .into_iter()
+                .map(Point::from)
+                .collect();
+
+            assert_eq!(
+                p.convex_hull(),
+                [(0, 0), (4, 0), (4, 4), (0, 4), (0, 0)]
+                    .into_iter()
+                    .map(Point::from)
+                    .collect(),
+            );
+        }
+
+        #[test]
+        fn degrades_collinear_to_extremes() {
+            let p: Polyline = [(3, 3), (0, 0), (1, 1), (-2, -2), (3, 3)]
+                .into_iter()
+                .map(Point::from)
+                .collect();
+
+            assert_eq!(
+                p.convex_hull(),
+                [(-2, -2), (3, 3), (-2, -2)]
+                    .into_iter()
+                    .map(Point::from)
+                    .collect(),
+            );
+        }
+
+        #[test]
+        fn keeps_single_vertex() {
+            let p = Polyline::new(Point { x: 1, y: 2 });
+
+            assert_eq!(p.convex_hull(), p);
+            assert_eq!(
+                Polyline::with_points(Point { x: 1, y: 2 }, vec![Point { x: 1, y: 2 }])
+                    .convex_hull(),
+                p,
+            );
+        }
+
+        #[test]
+        fn is_closed_and_convex() {
+            let p: Polyline = (0..20)
+                .map(|i| Point {
+                    x: (i * 7) % 11 - 5,
+                    y: (i * 13) % 17 - 8,
+                })
+                .collect();
+
+            let hull = p.convex_hull();
+
+            assert!(hull.is_closed());
+            assert!(hull.turn_angles().iter().all(|&a| a > 0.0));
+            assert!(p.iter().all(|&v| hull.contains(v)));
+        }
+    }
+}
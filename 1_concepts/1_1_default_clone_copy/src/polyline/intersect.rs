@@ -0,0 +1,235 @@
+use std::cmp::Ordering;
+
+use crate::{Point, Polyline};
+
+impl Polyline {
+    /// Checks whether any two non-adjacent segments of this [`Polyline`]
+    /// touch or cross each other.
+    ///
+    /// Adjacent segments always share their common vertex, so they only count
+    /// as intersecting when the second one folds back and overlaps the first.
+    /// The same rule applies to the last and the first segments of a closed
+    /// [`Polyline`] (ending at its `start`), so a simple closed shape is not
+    /// self-intersecting.
+    #[must_use]
+    pub fn is_self_intersecting(&self) -> bool {
+        let segments: Vec<_> = self.segments().collect();
+        let closed = segments.len() > 2 && self.is_closed();
+
+        segments.iter().enumerate().any(|(i, &(a, b))| {
+            (i + 1..segments.len()).any(|j| {
+                let (c, d) = segments[j];
+                if j == i + 1 {
+                    folds_back(a, b, d)
+                } else if closed && i == 0 && j == segments.len() - 1 {
+                    folds_back(c, a, b)
+                } else {
+                    segment_intersection(a, b, c, d).is_some()
+                }
+            })
+        })
+    }
+}
+
+/// Returns the orientation of the `a` -> `b` -> `c` turn: [`Ordering::Greater`]
+/// for a counter-clockwise one, [`Ordering::Less`] for a clockwise one, and
+/// [`Ordering::Equal`] if the points are collinear.
+pub(super) fn orientation(a: Point, b: Point, c: Point) -> Ordering {
+    let cross = (i128::from(b.x) - i128::from(a.x)) * (i128::from(c.y) - i128::from(a.y))
+        - (i128::from(b.y) - i128::from(a.y)) * (i128::from(c.x) - i128::from(a.x));
+    cross.cmp(&0)
+}
+
+/// Checks whether the `p` point, collinear with the `a`-`b` segment, lies
+/// within its bounding box.
+pub(super) fn on_segment(a: Point, b: Point, p: Point) -> bool {
+    (a.x.min(b.x)..=a.x.max(b.x)).contains(&p.x) && (a.y.min(b.y)..=a.y.max(b.y)).contains(&p.y)
+}
+
+/// Returns the common point of the `a0`-`a1` and `b0`-`b1` segments, if any.
+///
+/// The crossing point is computed with integer arithmetic, and rounded to the
+/// nearest integer coordinates (with halves rounded away from zero) when it's
+/// fractional. For collinear overlapping segments, the point of the overlap
+/// closest to the `a0` is returned, while parallel disjoint ones have none.
+///
+/// This is what [`Polyline::is_self_intersecting()`] tests segments with,
+/// while [`Polyline::clip_to_box()`] deliberately uses Liang–Barsky instead.
+#[must_use]
+pub fn segment_intersection(a0: Point, a1: Point, b0: Point, b1: Point) -> Option<Point> {
+    let delta = |from: Point, to: Point| {
+        (
+            i128::from(to.x) - i128::from(from.x),
+            i128::from(to.y) - i128::from(from.y),
+        )
+    };
+    let cross = |(x1, y1): (i128, i128), (x2, y2): (i128, i128)| x1 * y2 - y1 * x2;
+
+    let (r, s, ab) = (delta(a0, a1), delta(b0, b1), delta(a0, b0));
+    let denom = cross(r, s);
+    if denom == 0 {
+        let on_both = |p: Point| {
+            orientation(a0, a1, p) == Ordering::Equal
+                && on_segment(a0, a1, p)
+                && orientation(b0, b1, p) == Ordering::Equal
+                && on_segment(b0, b1, p)
+        };
+        return [a0, a1, b0, b1]
+            .into_iter()
+            .filter(|p| on_both(*p))
+            .min_by_key(|p| a0.squared_distance_to(*p));
+    }
+
+    let (t, u) = (cross(ab, s), cross(ab, r));
+    let (t, u, denom) = if denom < 0 {
+        (-t, -u, -denom)
+    } else {
+        (t, u, denom)
+    };
+    if !(0..=denom).contains(&t) || !(0..=denom).contains(&u) {
+        return None;
+    }
+    // Point lies on the segment, so it always fits into the `i32` range.
+    let at = |start: i32, delta: i128| {
+        let n = delta * t;
+        (i128::from(start) + (n + n.signum() * (denom / 2)) / denom) as i32
+    };
+    Some(Point {
+        x: at(a0.x, r.0),
+        y: at(a0.y, r.1),
+    })
+}
+
+/// Checks whether the `a`-`shared` and `shared`-`c` adjacent segments overlap
+/// beyond their `shared` vertex.
+fn folds_back(a: Point, shared: Point, c: Point) -> bool {
+    let dot = (i128::from(a.x) - i128::from(shared.x)) * (i128::from(c.x) - i128::from(shared.x))
+        + (i128::from(a.y) - i128::from(shared.y)) * (i128::from(c.y) - i128::from(shared.y));
+    orientation(a, shared, c) == Ordering::Equal && dot > 0
+}
+
+#[cfg(test)]
+mod spec {
+    use super::*;
+
+    mod is_self_intersecting {
+        use super::*;
+
+        fn polyline(vertices: &[(i32, i32)]) -> Polyline {
+            vertices.iter().map(|&(x, y)| Point { x, y }).collect()
+        }
+
+        #[test]
+        fn accepts_simple_path() {
+            assert!(!polyline(&[(0, 0)]).is_self_intersecting());
+            assert!(!polyline(&[(0, 0), (5, 0)]).is_self_intersecting());
+            assert!(!polyline(&[(0, 0), (5, 0), (5, 5), (0, 5)]).is_self_intersecting());
+        }
+
+        #[test]
+        fn detects_x_crossing() {
+            let p = polyline(&[(0, 0), (4, 4), (4, 0), (0, 4)]);
+
+            assert!(p.is_self_intersecting());
+        }
+
+        #[test]
+        fn accepts_closed_square() {
+            let p = polyline(&[(0, 0), (4, 0), (4, 4), (0, 4), (0, 0)]);
+
+            assert!(!p.is_self_intersecting());
+        }
+
+        #[test]
+        fn detects_touching_vertex() {
+            let p = polyline(&[(0, 0), (4, 0), (4, 4), (2, 0)]);
+
+            assert!(p.is_self_intersecting());
+        }
+
+        #[test]
+        fn detects_collinear_overlap() {
+            assert!(polyline(&[(0, 0), (4, 0), (2, 0)]).is_self_intersecting());
+            assert!(
+                polyline(&[(0, 0), (4, 0), (4, 2), (2, 2), (2, 0), (6, 0)]).is_self_intersecting()
+            );
+        }
+
+        #[test]
+        fn accepts_straight_continuation() {
+            assert!(!polyline(&[(0, 0), (2, 0), (4, 0)]).is_self_intersecting());
+        }
+    }
+
+    mod segment_intersection {
+        use super::*;
+
+        fn p(x: i32, y: i32) -> Point {
+            Point { x, y }
+        }
+
+        #[test]
+        fn finds_integer_crossing() {
+            assert_eq!(
+                segment_intersection(p(0, 0), p(4, 4), p(0, 4), p(4, 0)),
+                Some(p(2, 2))
+            );
+            assert_eq!(
+                segment_intersection(p(0, 0), p(4, 0), p(4, 0), p(4, 3)),
+                Some(p(4, 0))
+            );
+        }
+
+        #[test]
+        fn rounds_fractional_crossing() {
+            assert_eq!(
+                segment_intersection(p(0, 0), p(3, 3), p(0, 3), p(3, 0)),
+                Some(p(2, 2))
+            );
+            assert_eq!(
+                segment_intersection(p(0, 0), p(-3, -3), p(0, -3), p(-3, 0)),
+                Some(p(-2, -2))
+            );
+            assert_eq!(
+                segment_intersection(p(0, 0), p(10, 1), p(4, -5), p(4, 5)),
+                Some(p(4, 0))
+            );
+        }
+
+        #[test]
+        fn misses_non_crossing_segments() {
+            assert_eq!(
+                segment_intersection(p(0, 0), p(1, 1), p(3, 0), p(2, 1)),
+                None
+            );
+        }
+
+        #[test]
+        fn misses_parallel_segments() {
+            assert_eq!(
+                segment_intersection(p(0, 0), p(4, 0), p(0, 1), p(4, 1)),
+                None
+            );
+            assert_eq!(
+                segment_intersection(p(0, 0), p(2, 0), p(3, 0), p(5, 0)),
+                None
+            );
+        }
+
+        #[test]
+        fn returns_overlap_point_closest_to_start() {
+            assert_eq!(
+                segment_intersection(p(0, 0), p(4, 0), p(6, 0), p(2, 0)),
+                Some(p(2, 0))
+            );
+            assert_eq!(
+                segment_intersection(p(4, 0), p(0, 0), p(1, 0), p(6, 0)),
+                Some(p(4, 0))
+            );
+            assert_eq!(
+                segment_intersection(p(1, 1), p(1, 1), p(0, 0), p(2, 2)),
+                Some(p(1, 1))
+            );
+        }
+    }
+}
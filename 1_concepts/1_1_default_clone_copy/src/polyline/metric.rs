@@ -0,0 +1,115 @@
+use crate::{Point, Polyline};
+
+/// Distance metric between two [`Point`]s, pluggable into the [`Polyline`]
+/// measurements (like [`Polyline::total_length_with()`]).
+pub trait Metric {
+    /// Distance between the `a` and `b` [`Point`]s under this [`Metric`].
+    fn distance(&self, a: Point, b: Point) -> f64;
+}
+
+/// Euclidean (L2) [`Metric`], as used by [`Point::distance_to()`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Euclidean;
+
+impl Metric for Euclidean {
+    fn distance(&self, a: Point, b: Point) -> f64 {
+        a.distance_to(b)
+    }
+}
+
+/// Manhattan (L1) [`Metric`], as used by [`Point::manhattan_distance_to()`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Manhattan;
+
+impl Metric for Manhattan {
+    fn distance(&self, a: Point, b: Point) -> f64 {
+        a.manhattan_distance_to(b) as f64
+    }
+}
+
+/// Chebyshev (L∞) [`Metric`], being the largest of the per-axis distances,
+/// so it counts the king moves on a grid.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Chebyshev;
+
+impl Metric for Chebyshev {
+    fn distance(&self, a: Point, b: Point) -> f64 {
+        let dx = (i64::from(b.x) - i64::from(a.x)).abs();
+        let dy = (i64::from(b.y) - i64::from(a.y)).abs();
+        dx.max(dy) as f64
+    }
+}
+
+impl Polyline {
+    /// Cumulative length of all the segments of this [`Polyline`], measured
+    /// with the provided [`Metric`] instead of the [`Euclidean`] one used by
+    /// [`Polyline::total_length()`].
+    ///
+    /// It's `0.0` for a single-vertex [`Polyline`]. Unlike the
+    /// [`Polyline::total_length()`], it's not memoized.
+    #[must_use]
+    pub fn total_length_with(&self, metric: &impl Metric) -> f64 {
+        self.segments().map(|(a, b)| metric.distance(a, b)).sum()
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use super::*;
+
+    mod distance {
+        use super::*;
+
+        #[test]
+        fn measures_by_each_metric() {
+            let (a, b) = (Point { x: 1, y: -1 }, Point { x: 4, y: 3 });
+
+            assert_eq!(Euclidean.distance(a, b), 5.0);
+            assert_eq!(Manhattan.distance(a, b), 7.0);
+            assert_eq!(Chebyshev.distance(a, b), 4.0);
+        }
+
+        #[test]
+        fn never_overflows() {
+            let (a, b) = (Point { x: i32::MIN, y: 0 }, Point { x: i32::MAX, y: 0 });
+
+            assert_eq!(Manhattan.distance(a, b), f64::from(u32::MAX));
+            assert_eq!(Chebyshev.distance(a, b), f64::from(u32::MAX));
+        }
+    }
+
+    mod total_length_with {
+        use super::*;
+
+        fn polyline(vertices: &[(i32, i32)]) -> Polyline {
+            vertices.iter().copied().map(Point::from).collect()
+        }
+
+        #[test]
+        fn agrees_on_l_shaped_path() {
+            let p = polyline(&[(0, 0), (3, 0), (3, 4)]);
+
+            assert_eq!(p.total_length(), 7.0);
+            assert_eq!(p.total_length_with(&Euclidean), p.total_length());
+            assert_eq!(p.total_length_with(&Manhattan), p.total_length());
+            assert_eq!(p.total_length_with(&Chebyshev), 7.0);
+        }
+
+        #[test]
+        fn differs_on_diagonal_segments() {
+            let p = polyline(&[(0, 0), (3, 4), (3, 0)]);
+
+            assert_eq!(p.total_length_with(&Euclidean), p.total_length());
+            assert_eq!(p.total_length(), 9.0);
+            assert_eq!(p.total_length_with(&Manhattan), 11.0);
+            assert_eq!(p.total_length_with(&Chebyshev), 8.0);
+        }
+
+        #[test]
+        fn is_zero_for_single_vertex() {
+            let p = Polyline::new(Point { x: 5, y: 5 });
+
+            assert_eq!(p.total_length_with(&Manhattan), 0.0);
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{Point, Polyline};
+
+impl Polyline {
+    /// Maximum ratio of the miter length to the offset distance at the
+    /// corners of a [`Polyline::offset()`], matching the SVG default
+    /// `stroke-miterlimit`.
+    pub const MITER_LIMIT: f64 = 4.0;
+
+    /// Produces a parallel [`Polyline`] displaced by the provided `distance`
+    /// to the left of this one (or to the right, if it's negative), assuming
+    /// the `y` axis points up.
+    ///
+    /// The endpoints are displaced along the normals of their segments, while
+    /// the interior vertices are displaced with miter joins, along the
+    /// bisectors of the adjacent segment normals, so the parallel segments
+    /// keep the `distance` from the original ones. At sharp corners the
+    /// miter grows unboundedly, so it's cut to [`Polyline::MITER_LIMIT`]
+    /// times the `distance`, and a full 180° turn is displaced along the
+    /// incoming normal only.
+    ///
+    /// Coincident consecutive vertices are merged first, as they have no
+    /// direction, and the resulting coordinates are rounded to the nearest
+    /// integers, with halves rounded away from zero.
+    #[must_use]
+    pub fn offset(&self, distance: f64) -> Self {
+        let mut deduped = self.clone();
+        deduped.dedup_consecutive();
+        let vertices: Vec<_> = deduped.iter().copied().collect();
+        let normals: Vec<_> = deduped.segments().map(|(a, b)| normal(a, b)).collect();
+        let (Some(&first), Some(&last)) = (normals.first(), normals.last()) else {
+            return deduped;
+        };
+
+        let displace = |p: Point, (nx, ny): (f64, f64), len: f64| Point {
+            x: (f64::from(p.x) + nx * len).round() as i32,
+            y: (f64::from(p.y) + ny * len).round() as i32,
+        };
+        let mut offset = Self::new(displace(vertices[0], first, distance));
+        for (i, w) in normals.windows(2).enumerate() {
+            let [n1, n2] = [w[0], w[1]];
+            let (mx, my) = (n1.0 + n2.0, n1.1 + n2.1);
+            let m_len = mx.hypot(my);
+            let p = vertices[i + 1];
+            if m_len < 1e-9 {
+                offset.push(displace(p, n1, distance));
+                continue;
+            }
+            let bisector = (mx / m_len, my / m_len);
+            let cos_half = bisector.0 * n1.0 + bisector.1 * n1.1;
+            let miter = (1.0 / cos_half).min(Self::MITER_LIMIT);
+            offset.push(displace(p, bisector, distance * miter));
+        }
+        offset.push(displace(vertices[vertices.len() - 1], last, distance));
+        offset
+    }
+}
+
+/// Unit normal pointing to the left of the `a` -> `b` direction.
+fn normal(a: Point, b: Point) -> (f64, f64) {
+    let (dx, dy) = (
+        f64::from(b.x) - f64::from(a.x),
+        f64::from(b.y) - f64::from(a.y),
+    );
+    let len = dx.hypot(dy);
+    (-dy / len, dx / len)
+}
+
+#[cfg(test)]
+mod spec {
+    use super::*;
+
+    mod offset {
+        use super::*;
+
+        fn corner() -> Polyline {
+            [(0, 0), (10, 0), (10, 10)]
+                .into_iter()
+                .map(Point::from)
+                .collect()
+        }
+
+        #[test]
+        fn shifts_straight_segment() {
+            let p = Polyline::with_points(Point { x: 0, y: 0 }, vec![Point { x: 10, y: 0 }]);
+
+            assert_eq!(
+                p.offset(2.0),
+                Polyline::with_points(Point { x: 0, y: 2 }, vec![Point { x: 10, y: 2 }]),
+            );
+            assert_eq!(
+                p.offset(-3.0),
+                Polyline::with_points(Point { x: 0, y: -3 }, vec![Point { x: 10, y: -3 }]),
+            );
+        }
+
+        #[test]
+        fn shifts_diagonal_segment() {
+            let p = Polyline::with_points(Point { x: 0, y: 0 }, vec![Point { x: 30, y: 40 }]);
+
+            assert_eq!(
+                p.offset(5.0),
+                Polyline::with_points(Point { x: -4, y: 3 }, vec![Point { x: 26, y: 43 }]),
+            );
+        }
+
+        #[test]
+        fn miters_right_angle() {
+            assert_eq!(
+                corner().offset(2.0),
+                [(0, 2), (8, 2), (8, 10)]
+                    .into_iter()
+                    .map(Point::from)
+                    .collect(),
+            );
+            assert_eq!(
+                corner().offset(-2.0),
+                [(0, -2), (12, -2), (12, 10)]
+                    .into_iter()
+                    .map(Point::from)
+                    .collect(),
+            );
+        }
+
+        #[test]
+        fn limits_miter_at_sharp_corner() {
+            let p: Polyline = [(0, 0), (100, 0), (0, 1)]
+                .into_iter()
+                .map(Point::from)
+                .collect();
+
+            let corner = p.offset(-10.0).iter().nth(1).copied().unwrap();
+
+            let limit = Polyline::MITER_LIMIT * 10.0;
+            assert!(corner.distance_to(Point { x: 100, y: 0 }) <= limit + 1.0);
+            assert!(corner.x > 100);
+        }
+
+        #[test]
+        fn shifts_hairpin_along_incoming_normal() {
+            let p: Polyline = [(0, 0), (10, 0), (0, 0)]
+                .into_iter()
+                .map(Point::from)
+                .collect();
+
+            assert_eq!(p.offset(1.0).iter().nth(1), Some(&Point { x: 10, y: 1 }));
+        }
+
+        #[test]
+        fn merges_coincident_vertices() {
+            let p: Polyline = [(0, 0), (0, 0), (10, 0), (10, 0)]
+                .into_iter()
+                .map(Point::from)
+                .collect();
+
+            assert_eq!(
+                p.offset(1.0),
+                Polyline::with_points(Point { x: 0, y: 1 }, vec![Point { x: 10, y: 1 }]),
+            );
+            assert_eq!(
+                Polyline::new(Point::default()).offset(5.0),
+                Polyline::new(Point::default())
+            );
+        }
+    }
+}
@@ -0,0 +1,777 @@
+use std::{cmp::Reverse, collections::BinaryHeap, iter, num::NonZero, panic, thread};
+
+use crate::{Point, Polyline, polyline::PolylineError};
+
+impl Polyline {
+    /// Simplifies this [`Polyline`] with the [Ramer–Douglas–Peucker][1]
+    /// algorithm.
+    ///
+    /// The first and the last vertices are always kept, while any other one is
+    /// discarded if its perpendicular distance to the retained segment is
+    /// below the `epsilon`.
+    ///
+    /// [1]: https://en.wikipedia.org/wiki/Ramer%E2%80%93Douglas%E2%80%93Peucker_algorithm
+    #[must_use]
+    pub fn simplify(&self, epsilon: f64) -> Self {
+        self.simplify_with(epsilon, perpendicular_distance)
+    }
+
+    /// Version of [`Polyline::simplify()`] tracking the pending vertex ranges
+    /// on an explicit heap-allocated stack instead of recursing, so it cannot
+    /// overflow the call stack on pathological inputs (like tens of thousands
+    /// of nearly-collinear vertices), still producing the identical output.
+    #[must_use]
+    pub fn simplify_iterative(&self, epsilon: f64) -> Self {
+        let vertices: Vec<_> = self.iter().copied().collect();
+        let mut keep = vec![false; vertices.len()];
+        keep[0] = true;
+        keep[vertices.len() - 1] = true;
+
+        let mut pending = vec![(0, vertices.len() - 1)];
+        while let Some((first, last)) = pending.pop() {
+            if let Some(i) = farthest(&vertices[first..=last], epsilon, &perpendicular_distance) {
+                let i = first + i;
+                keep[i] = true;
+                pending.push((i, last));
+                pending.push((first, i));
+            }
+        }
+
+        vertices
+            .into_iter()
+            .zip(keep)
+            .filter_map(|(p, keep)| keep.then_some(p))
+            .collect()
+    }
+
+    /// Simplifies the provided stream of `points` lazily, with the
+    /// [Reumann–Witkam][1] algorithm, so inputs not fitting into memory can be
+    /// simplified on the fly.
+    ///
+    /// This only approximates [`Polyline::simplify()`], as the Ramer–Douglas–
+    /// Peucker algorithm needs all the points at once: instead, the points are
+    /// discarded while their perpendicular distance to the line through the
+    /// last emitted point and its successor doesn't exceed the `epsilon`, and
+    /// the last point within that strip is emitted once some point leaves it.
+    ///
+    /// The first and the last points are always emitted (a single one for a
+    /// single-point stream, and none for an empty one).
+    ///
+    /// [1]: https://en.wikipedia.org/wiki/Reumann%E2%80%93Witkam_algorithm
+    pub fn simplify_stream(
+        points: impl IntoIterator<Item = Point>,
+        epsilon: f64,
+    ) -> impl Iterator<Item = Point> {
+        let mut points = points.into_iter();
+        // Last emitted point, along with the one defining the strip direction.
+        let mut anchor: Option<(Point, Option<Point>)> = None;
+        // Last point read, but not emitted yet.
+        let mut pending = None;
+        iter::from_fn(move || {
+            let Some((from, towards)) = &mut anchor else {
+                let first = points.next()?;
+                anchor = Some((first, None));
+                return Some(first);
+            };
+            for p in points.by_ref() {
+                match towards {
+                    Some(to) if perpendicular_distance(p, *from, *to) > epsilon => {
+                        let last = pending.replace(p)?;
+                        anchor = Some((last, Some(p)));
+                        return Some(last);
+                    }
+                    Some(_) => {}
+                    None if p == *from => {}
+                    None => *towards = Some(p),
+                }
+                pending = Some(p);
+            }
+            pending.take()
+        })
+    }
+
+    /// Simplifies this [`Polyline`] with the [Ramer–Douglas–Peucker][1]
+    /// algorithm, measuring distances with the provided `dist` metric instead
+    /// of the perpendicular one used by [`Polyline::simplify()`].
+    ///
+    /// The `dist(p, a, b)` should return the distance from the `p` [`Point`]
+    /// to the retained segment between the `a` and `b` ones. The first and the
+    /// last vertices are always kept, while any other one is discarded if its
+    /// `dist` is below the `epsilon`.
+    ///
+    /// [1]: https://en.wikipedia.org/wiki/Ramer%E2%80%93Douglas%E2%80%93Peucker_algorithm
+    #[must_use]
+    pub fn simplify_with<F>(&self, epsilon: f64, dist: F) -> Self
+    where
+        F: Fn(Point, Point, Point) -> f64,
+    {
+        let mut kept = self.retained_indices(epsilon, &dist).into_iter().peekable();
+        self.iter()
+            .enumerate()
+            .filter_map(|(i, p)| kept.next_if_eq(&i).map(|_| *p))
+            .collect()
+    }
+
+    /// Returns the ascending indices (in the `start`-first order) of the
+    /// vertices retained by [`Polyline::simplify()`], so they can be mapped
+    /// back to the original ones.
+    ///
+    /// The first and the last indices are always present.
+    #[must_use]
+    pub fn simplify_indices(&self, epsilon: f64) -> Vec<usize> {
+        self.retained_indices(epsilon, &perpendicular_distance)
+    }
+
+    /// Returns the ascending indices (in the `start`-first order) of the
+    /// vertices retained by the [Ramer–Douglas–Peucker][1] algorithm with the
+    /// provided `dist` metric.
+    ///
+    /// [1]: https://en.wikipedia.org/wiki/Ramer%E2%80%93Douglas%E2%80%93Peucker_algorithm
+    fn retained_indices<F>(&self, epsilon: f64, dist: &F) -> Vec<usize>
+    where
+        F: Fn(Point, Point, Point) -> f64,
+    {
+        let vertices: Vec<_> = self.iter().copied().collect();
+        let mut keep = vec![false; vertices.len()];
+        keep[0] = true;
+        keep[vertices.len() - 1] = true;
+        rdp(&vertices, epsilon, dist, &mut keep);
+
+        keep.into_iter()
+            .enumerate()
+            .filter_map(|(i, keep)| keep.then_some(i))
+            .collect()
+    }
+
+    /// Simplifies this [`Polyline`] to at most `max_points` vertices, with the
+    /// smallest [`Polyline::simplify()`] `epsilon` fitting that budget, found
+    /// by a binary search.
+    ///
+    /// The first and the last vertices are always kept, so the result has at
+    /// least 2 vertices even for a smaller `max_points`, unless this
+    /// [`Polyline`] has a single vertex. A [`Polyline`] already fitting the
+    /// budget is returned as is.
+    #[must_use]
+    pub fn simplify_to(&self, max_points: usize) -> Self {
+        if self.len() <= max_points.max(2) {
+            return self.clone();
+        }
+        // No vertex lies farther than the bounding box diagonal from any
+        // segment between other ones, so this `epsilon` keeps only endpoints.
+        let (min, max) = self.bounding_box();
+        let (mut lo, mut hi) = (0.0, min.distance_to(max) + 1.0);
+        for _ in 0..64 {
+            let mid = f64::midpoint(lo, hi);
+            if mid <= lo || mid >= hi {
+                break;
+            }
+            if self.simplify_indices(mid).len() <= max_points {
+                hi = mid;
+            } else {
+                lo = mid;
+            }
+        }
+        self.simplify(hi)
+    }
+
+    /// Simplifies each of the provided `polylines` with the
+    /// [`Polyline::simplify()`] in parallel, splitting them into contiguous
+    /// chunks over the [`thread::available_parallelism()`] scoped threads.
+    ///
+    /// Results are returned in the order of the `polylines`, identical to the
+    /// sequential [`Polyline::simplify()`] of each of them.
+    ///
+    /// # Panics
+    ///
+    /// If simplifying any of the `polylines` panics, the panic is propagated
+    /// once all the threads are joined.
+    #[must_use]
+    pub fn simplify_many(polylines: &[Self], epsilon: f64) -> Vec<Self> {
+        let threads = thread::available_parallelism().map_or(1, NonZero::get);
+        let chunk = polylines.len().div_ceil(threads).max(1);
+        thread::scope(|s| {
+            let workers: Vec<_> = polylines
+                .chunks(chunk)
+                .map(|chunk| {
+                    s.spawn(move || {
+                        chunk
+                            .iter()
+                            .map(|p| p.simplify(epsilon))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            workers
+                .into_iter()
+                .flat_map(|w| w.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+                .collect()
+        })
+    }
+
+    /// Simplifies this [`Polyline`] with the [Visvalingam–Whyatt][1]
+    /// algorithm, repeatedly removing the interior vertex forming the
+    /// smallest triangle with its remaining neighbors, until all of them form
+    /// triangles of area exceeding the `min_area`.
+    ///
+    /// The first and the last vertices are always kept, so a degenerate
+    /// (zero-area) triangle of a collinear or duplicated vertex is always
+    /// removed first. Areas are compared exactly, while ties are resolved in
+    /// the `start`-first order. Unlike the [`Polyline::simplify()`], this
+    /// tends to preserve the overall shape better, which suits cartographic
+    /// data.
+    ///
+    /// [1]: https://en.wikipedia.org/wiki/Visvalingam%E2%80%93Whyatt_algorithm
+    #[must_use]
+    pub fn simplify_visvalingam(&self, min_area: f64) -> Self {
+        let vertices: Vec<_> = self.iter().copied().collect();
+        let last = vertices.len() - 1;
+        let mut prev: Vec<_> = (0..=last).map(|i| i.saturating_sub(1)).collect();
+        let mut next: Vec<_> = (0..=last).map(|i| (i + 1).min(last)).collect();
+        let mut kept = vec![true; vertices.len()];
+
+        let area = |prev: usize, i: usize, next: usize| {
+            doubled_triangle_area(vertices[prev], vertices[i], vertices[next])
+        };
+        let mut areas: Vec<_> = (0..=last).map(|i| area(prev[i], i, next[i])).collect();
+        let mut heap: BinaryHeap<_> = (1..last).map(|i| Reverse((areas[i], i))).collect();
+
+        while let Some(Reverse((doubled, i))) = heap.pop() {
+            if !kept[i] || doubled != areas[i] {
+                continue; // stale entry, superseded after removing a neighbor
+            }
+            if doubled as f64 / 2.0 > min_area {
+                break;
+            }
+            kept[i] = false;
+            let (p, n) = (prev[i], next[i]);
+            next[p] = n;
+            prev[n] = p;
+            for j in [p, n] {
+                if j != 0 && j != last {
+                    areas[j] = area(prev[j], j, next[j]);
+                    heap.push(Reverse((areas[j], j)));
+                }
+            }
+        }
+
+        vertices
+            .into_iter()
+            .zip(kept)
+            .filter_map(|(p, kept)| kept.then_some(p))
+            .collect()
+    }
+
+    /// Fallible version of [`Polyline::simplify()`].
+    ///
+    /// # Errors
+    ///
+    /// [`PolylineError::InvalidEpsilon`] if the `epsilon` is negative or NaN.
+    pub fn try_simplify(&self, epsilon: f64) -> Result<Self, PolylineError> {
+        if epsilon.is_nan() || epsilon < 0.0 {
+            return Err(PolylineError::InvalidEpsilon);
+        }
+        Ok(self.simplify(epsilon))
+    }
+}
+
+/// Marks the `keep`-ing vertices strictly between the first and the last ones
+/// of the provided `vertices`, measuring distances with the `dist` metric.
+fn rdp<F>(vertices: &[Point], epsilon: f64, dist: &F, keep: &mut [bool])
+where
+    F: Fn(Point, Point, Point) -> f64,
+{
+    if let Some(i) = farthest(vertices, epsilon, dist) {
+        keep[i] = true;
+        rdp(&vertices[..=i], epsilon, dist, &mut keep[..=i]);
+        rdp(&vertices[i..], epsilon, dist, &mut keep[i..]);
+    }
+}
+
+/// Returns the index of the vertex strictly between the first and the last
+/// ones of the provided `vertices`, lying farthest from the segment between
+/// them by the `dist` metric, if that distance exceeds the `epsilon`.
+fn farthest<F>(vertices: &[Point], epsilon: f64, dist: &F) -> Option<usize>
+where
+    F: Fn(Point, Point, Point) -> f64,
+{
+    let [first, .., last] = vertices else {
+        return None;
+    };
+
+    vertices[1..vertices.len() - 1]
+        .iter()
+        .map(|p| dist(*p, *first, *last))
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .filter(|(_, distance)| *distance > epsilon)
+        .map(|(i, _)| i + 1)
+}
+
+/// Doubled area of the triangle formed by the `a`, `b` and `c` [`Point`]s,
+/// computed exactly in [`i128`].
+fn doubled_triangle_area(a: Point, b: Point, c: Point) -> i128 {
+    let (abx, aby) = (
+        i128::from(b.x) - i128::from(a.x),
+        i128::from(b.y) - i128::from(a.y),
+    );
+    let (acx, acy) = (
+        i128::from(c.x) - i128::from(a.x),
+        i128::from(c.y) - i128::from(a.y),
+    );
+    (abx * acy - aby * acx).abs()
+}
+
+/// Distance from the `p` [`Point`] to the line passing through the `a` and `b`
+/// ones (or to the `a` one, if they coincide).
+fn perpendicular_distance(p: Point, a: Point, b: Point) -> f64 {
+    if a == b {
+        return p.distance_to(a);
+    }
+    let (dx, dy) = (
+        f64::from(b.x) - f64::from(a.x),
+        f64::from(b.y) - f64::from(a.y),
+    );
+    let (px, py) = (
+        f64::from(p.x) - f64::from(a.x),
+        f64::from(p.y) - f64::from(a.y),
+    );
+    (dx * py - dy * px).abs() / dx.hypot(dy)
+}
+
+#[cfg(test)]
+mod spec {
+    use super::*;
+
+    mod simplify {
+        use super::*;
+
+        #[test]
+        fn collapses_straight_line_to_endpoints() {
+            let p: Polyline = (0..10).map(|i| Point { x: i, y: 2 * i }).collect();
+
+            assert_eq!(
+                p.simplify(0.5),
+                Polyline::with_points(Point { x: 0, y: 0 }, vec![Point { x: 9, y: 18 }]),
+            );
+        }
+
+        #[test]
+        fn keeps_zig_zag_for_small_epsilon() {
+            let p: Polyline = (0..10).map(|i| Point { x: i, y: i % 2 * 5 }).collect();
+
+            assert_eq!(p.simplify(0.1), p);
+        }
+
+        #[test]
+        fn drops_near_collinear_vertices() {
+            let p = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![
+                    Point { x: 5, y: 1 },
+                    Point { x: 10, y: 0 },
+                    Point { x: 10, y: 10 },
+                ],
+            );
+
+            assert_eq!(
+                p.simplify(2.0),
+                Polyline::with_points(
+                    Point { x: 0, y: 0 },
+                    vec![Point { x: 10, y: 0 }, Point { x: 10, y: 10 }],
+                ),
+            );
+        }
+
+        #[test]
+        fn keeps_single_vertex() {
+            let p = Polyline::new(Point { x: 1, y: 1 });
+
+            assert_eq!(p.simplify(100.0), p);
+        }
+    }
+
+    mod simplify_iterative {
+        use super::*;
+
+        #[test]
+        fn matches_recursive_simplify() {
+            let inputs: [Polyline; 4] = [
+                (0..10).map(|i| Point { x: i, y: 2 * i }).collect(),
+                (0..10).map(|i| Point { x: i, y: i % 2 * 5 }).collect(),
+                (0..30).map(|i| Point { x: i, y: i % 3 * i }).collect(),
+                Polyline::new(Point { x: 1, y: 1 }),
+            ];
+
+            for p in inputs {
+                for epsilon in [0.0, 0.5, 1.0, 5.0, 100.0] {
+                    assert_eq!(
+                        p.simplify_iterative(epsilon),
+                        p.simplify(epsilon),
+                        "for {p:?} with {epsilon} epsilon",
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn completes_on_small_stack() {
+            // The recursive `simplify()` overflows such a stack on this input.
+            const STACK_SIZE: usize = 64 * 1024;
+
+            let p: Polyline = (0..2_000)
+                .map(|i| Point {
+                    x: i,
+                    y: (i * 7919) % 13 - 6,
+                })
+                .collect();
+            let expected = p.simplify(1.0);
+
+            let simplified = thread::Builder::new()
+                .stack_size(STACK_SIZE)
+                .spawn(move || p.simplify_iterative(1.0))
+                .unwrap()
+                .join()
+                .unwrap();
+
+            assert!(simplified.len() < 2_000);
+            assert_eq!(simplified, expected);
+        }
+    }
+
+    mod simplify_stream {
+        use super::*;
+
+        #[test]
+        fn reduces_noisy_straight_line() {
+            let noisy = (0..1000).map(|i| Point {
+                x: 10 * i,
+                y: (i * 7) % 3 - 1,
+            });
+
+            let simplified: Vec<_> = Polyline::simplify_stream(noisy, 10.0).collect();
+
+            assert_eq!(simplified.first(), Some(&Point { x: 0, y: -1 }));
+            assert_eq!(simplified.last(), Some(&Point { x: 9990, y: -1 }));
+            assert!(simplified.len() < 1000 / 5, "{} points", simplified.len());
+        }
+
+        #[test]
+        fn emits_endpoints_and_corners() {
+            let l_shape = (0..=10)
+                .map(|x| Point { x, y: 0 })
+                .chain((1..=10).map(|y| Point { x: 10, y }));
+
+            let simplified: Polyline = Polyline::simplify_stream(l_shape, 0.5).collect();
+
+            assert_eq!(
+                simplified,
+                [(0, 0), (10, 0), (10, 10)]
+                    .into_iter()
+                    .map(Point::from)
+                    .collect(),
+            );
+        }
+
+        #[test]
+        fn keeps_every_vertex_of_zig_zag() {
+            let p: Polyline = (0..10).map(|i| Point { x: i, y: i % 2 * 5 }).collect();
+
+            let simplified: Polyline = Polyline::simplify_stream(p.clone(), 0.1).collect();
+
+            assert_eq!(simplified, p);
+        }
+
+        #[test]
+        fn handles_short_streams() {
+            let p = Point { x: 1, y: 2 };
+
+            assert_eq!(Polyline::simplify_stream([], 1.0).count(), 0);
+            assert!(Polyline::simplify_stream([p], 1.0).eq([p]));
+            assert!(Polyline::simplify_stream([p, p, p], 1.0).eq([p, p]));
+        }
+    }
+
+    mod simplify_many {
+        use super::*;
+
+        #[test]
+        fn matches_sequential_simplify() {
+            let polylines: Vec<Polyline> = (0..100)
+                .map(|n| {
+                    (0..n + 1)
+                        .map(|i| Point {
+                            x: i,
+                            y: (i * n * 7) % 11 - 5,
+                        })
+                        .collect()
+                })
+                .collect();
+
+            for epsilon in [0.0, 1.0, 3.0] {
+                assert_eq!(
+                    Polyline::simplify_many(&polylines, epsilon),
+                    polylines
+                        .iter()
+                        .map(|p| p.simplify(epsilon))
+                        .collect::<Vec<_>>(),
+                );
+            }
+        }
+
+        #[test]
+        fn returns_nothing_for_no_polylines() {
+            assert!(Polyline::simplify_many(&[], 1.0).is_empty());
+        }
+    }
+
+    mod simplify_visvalingam {
+        use super::*;
+
+        #[test]
+        fn preserves_endpoints() {
+            let p: Polyline = (0..20).map(|i| Point { x: i, y: i % 3 }).collect();
+
+            let simplified = p.simplify_visvalingam(1000.0);
+
+            assert_eq!(
+                simplified,
+                Polyline::with_points(Point { x: 0, y: 0 }, vec![Point { x: 19, y: 1 }]),
+            );
+        }
+
+        #[test]
+        fn removes_degenerate_triangle_first() {
+            // `(5, 0)` is collinear with its neighbors, while `(10, 2)` forms
+            // the smallest non-degenerate triangle.
+            let p: Polyline = [(0, 0), (5, 0), (10, 0), (10, 2), (11, 10)]
+                .into_iter()
+                .map(Point::from)
+                .collect();
+
+            assert_eq!(
+                p.simplify_visvalingam(0.0),
+                [(0, 0), (10, 0), (10, 2), (11, 10)]
+                    .into_iter()
+                    .map(Point::from)
+                    .collect(),
+            );
+            assert_eq!(
+                p.simplify_visvalingam(5.0),
+                [(0, 0), (10, 0), (11, 10)]
+                    .into_iter()
+                    .map(Point::from)
+                    .collect(),
+            );
+        }
+
+        #[test]
+        fn removes_duplicated_vertices() {
+            let p: Polyline = [(0, 0), (3, 3), (3, 3), (6, 0)]
+                .into_iter()
+                .map(Point::from)
+                .collect();
+
+            assert_eq!(
+                p.simplify_visvalingam(0.0),
+                [(0, 0), (3, 3), (6, 0)]
+                    .into_iter()
+                    .map(Point::from)
+                    .collect(),
+            );
+        }
+
+        #[test]
+        fn keeps_short_polylines() {
+            let p = Polyline::new(Point { x: 1, y: 1 });
+            let segment = Polyline::with_points(Point { x: 1, y: 1 }, vec![Point { x: 2, y: 2 }]);
+
+            assert_eq!(p.simplify_visvalingam(100.0), p);
+            assert_eq!(segment.simplify_visvalingam(100.0), segment);
+        }
+    }
+
+    mod simplify_with {
+        use super::*;
+
+        /// Vertical distance from the `p` [`Point`] to the non-vertical line
+        /// passing through the `a` and `b` ones.
+        fn vertical_distance(p: Point, a: Point, b: Point) -> f64 {
+            let t = f64::from(p.x - a.x) / f64::from(b.x - a.x);
+            (f64::from(a.y) + t * f64::from(b.y - a.y) - f64::from(p.y)).abs()
+        }
+
+        #[test]
+        fn matches_simplify_with_perpendicular_metric() {
+            let p: Polyline = (0..10).map(|i| Point { x: i, y: i % 3 * i }).collect();
+
+            for epsilon in [0.0, 1.0, 5.0] {
+                assert_eq!(
+                    p.simplify_with(epsilon, perpendicular_distance),
+                    p.simplify(epsilon),
+                );
+            }
+        }
+
+        #[test]
+        fn retains_vertices_by_custom_metric() {
+            let p = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![Point { x: 1, y: 5 }, Point { x: 2, y: 20 }],
+            );
+
+            assert_eq!(
+                p.simplify(1.0),
+                Polyline::with_points(Point { x: 0, y: 0 }, vec![Point { x: 2, y: 20 }]),
+            );
+            assert_eq!(p.simplify_with(1.0, vertical_distance), p);
+        }
+    }
+
+    mod simplify_to {
+        use super::*;
+
+        fn polyline() -> Polyline {
+            (0..40)
+                .map(|i| Point {
+                    x: i * 3,
+                    y: (i * 17) % 23 - (i % 5) * 4,
+                })
+                .collect()
+        }
+
+        #[test]
+        fn never_exceeds_budget() {
+            let p = polyline();
+
+            for max_points in 2..p.len() {
+                let simplified = p.simplify_to(max_points);
+
+                assert!(simplified.len() <= max_points, "for {max_points} budget");
+                assert!(simplified.len() >= 2);
+            }
+        }
+
+        #[test]
+        fn preserves_endpoints() {
+            let p = polyline();
+            let last = *p.iter().last().unwrap();
+
+            for max_points in [0, 1, 2, 5, 17] {
+                let simplified = p.simplify_to(max_points);
+
+                assert_eq!(simplified.iter().next(), p.iter().next());
+                assert_eq!(simplified.iter().last(), Some(&last));
+            }
+            assert_eq!(p.simplify_to(0).len(), 2);
+        }
+
+        #[test]
+        fn keeps_polyline_within_budget() {
+            let p = polyline();
+
+            assert_eq!(p.simplify_to(p.len()), p);
+            assert_eq!(p.simplify_to(usize::MAX), p);
+        }
+
+        #[test]
+        fn uses_budget_fully_when_possible() {
+            let p = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![
+                    Point { x: 5, y: 1 },
+                    Point { x: 10, y: 0 },
+                    Point { x: 10, y: 10 },
+                ],
+            );
+
+            assert_eq!(p.simplify_to(3), p.simplify(2.0));
+        }
+
+        #[test]
+        fn keeps_single_vertex() {
+            let p = Polyline::new(Point { x: 1, y: 1 });
+
+            assert_eq!(p.simplify_to(0), p);
+        }
+    }
+
+    mod simplify_indices {
+        use super::*;
+
+        #[test]
+        fn returns_endpoints_of_straight_line() {
+            let p: Polyline = (0..10).map(|i| Point { x: i, y: 2 * i }).collect();
+
+            assert_eq!(p.simplify_indices(0.5), [0, p.len() - 1]);
+        }
+
+        #[test]
+        fn always_keeps_first_and_last() {
+            let p = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![
+                    Point { x: 5, y: 1 },
+                    Point { x: 10, y: 0 },
+                    Point { x: 10, y: 10 },
+                ],
+            );
+
+            for epsilon in [0.0, 2.0, 100.0] {
+                let indices = p.simplify_indices(epsilon);
+
+                assert_eq!(indices.first(), Some(&0));
+                assert_eq!(indices.last(), Some(&(p.len() - 1)));
+            }
+            assert_eq!(p.simplify_indices(2.0), [0, 2, 3]);
+        }
+
+        #[test]
+        fn matches_simplified_vertices() {
+            let p = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![
+                    Point { x: 3, y: 4 },
+                    Point { x: 6, y: 0 },
+                    Point { x: 7, y: 1 },
+                    Point { x: 12, y: 0 },
+                ],
+            );
+            let vertices: Vec<_> = p.iter().copied().collect();
+
+            let indices = p.simplify_indices(1.5);
+
+            assert_eq!(
+                indices.iter().map(|&i| vertices[i]).collect::<Polyline>(),
+                p.simplify(1.5),
+            );
+        }
+
+        #[test]
+        fn returns_single_index_for_single_vertex() {
+            assert_eq!(Polyline::new(Point::default()).simplify_indices(1.0), [0]);
+        }
+    }
+
+    mod try_simplify {
+        use super::*;
+
+        #[test]
+        fn simplifies_with_valid_epsilon() {
+            let p = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![Point { x: 5, y: 1 }, Point { x: 10, y: 0 }],
+            );
+
+            assert_eq!(p.try_simplify(2.0), Ok(p.simplify(2.0)));
+            assert_eq!(p.try_simplify(0.0), Ok(p.clone()));
+        }
+
+        #[test]
+        fn errors_on_invalid_epsilon() {
+            let p = Polyline::new(Point::default());
+
+            assert_eq!(p.try_simplify(-1.0), Err(PolylineError::InvalidEpsilon));
+            assert_eq!(p.try_simplify(f64::NAN), Err(PolylineError::InvalidEpsilon));
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use std::{
+    iter,
+    ops::{Bound, RangeBounds},
+};
+
+use super::bounds;
+use crate::{Point, Polyline};
+
+/// Borrowed non-empty contiguous range of a [`Polyline`]'s vertices.
+///
+/// Like [`Polyline`] itself, the first vertex is kept apart from the rest, so
+/// the "at least one point" invariant holds structurally, and no allocation
+/// is needed to view any vertex range of the owning [`Polyline`].
+#[derive(Clone, Copy, Debug)]
+pub struct PolylineSlice<'a> {
+    first: &'a Point,
+    rest: &'a [Point],
+}
+
+impl<'a> PolylineSlice<'a> {
+    /// Number of vertices in this [`PolylineSlice`], which is never zero.
+    #[expect(
+        clippy::len_without_is_empty,
+        reason = "`PolylineSlice` is never empty"
+    )]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        1 + self.rest.len()
+    }
+
+    /// Iterates over all the vertices of this [`PolylineSlice`], in the order
+    /// of the owning [`Polyline`].
+    pub fn iter(&self) -> impl Iterator<Item = &'a Point> + use<'a> {
+        iter::once(self.first).chain(self.rest)
+    }
+
+    /// Axis-aligned bounding box of this [`PolylineSlice`], returned as its
+    /// `(min, max)` corners.
+    #[must_use]
+    pub fn bounding_box(&self) -> (Point, Point) {
+        bounds(*self.first, self.rest)
+    }
+}
+
+impl Polyline {
+    /// Borrows the vertices within the provided `range` (in the `start`-first
+    /// order) of this [`Polyline`] as a [`PolylineSlice`], without allocating.
+    ///
+    /// # Panics
+    ///
+    /// If the `range` is empty or exceeds the `len()` of this [`Polyline`].
+    #[must_use]
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> PolylineSlice<'_> {
+        let from = match range.start_bound() {
+            Bound::Included(&i) => i,
+            Bound::Excluded(&i) => i.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let to = match range.end_bound() {
+            Bound::Included(&i) => i.saturating_add(1),
+            Bound::Excluded(&i) => i,
+            Bound::Unbounded => self.len(),
+        };
+        assert!(
+            from < to && to <= self.len(),
+            "vertex range (is {from}..{to}) should be non-empty and <= len (is {})",
+            self.len(),
+        );
+        match from {
+            0 => PolylineSlice {
+                first: &self.start,
+                rest: &self.points[..to - 1],
+            },
+            _ => PolylineSlice {
+                first: &self.points[from - 1],
+                rest: &self.points[from..to - 1],
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use super::*;
+
+    fn polyline() -> Polyline {
+        [(0, 0), (5, -2), (3, 7), (-4, 1), (2, 2)]
+            .into_iter()
+            .map(Point::from)
+            .collect()
+    }
+
+    mod slice {
+        use super::*;
+
+        #[test]
+        fn borrows_whole_polyline() {
+            let p = polyline();
+
+            let s = p.slice(..);
+
+            assert_eq!(s.len(), p.len());
+            assert!(s.iter().eq(p.iter()));
+            assert_eq!(s.bounding_box(), p.bounding_box());
+        }
+
+        #[test]
+        fn borrows_inner_range() {
+            let p = polyline();
+
+            let s = p.slice(1..=2);
+
+            assert_eq!(s.len(), 2);
+            assert!(s.iter().eq(p.iter().skip(1).take(2)));
+            assert_eq!(
+                s.bounding_box(),
+                (Point { x: 3, y: -2 }, Point { x: 5, y: 7 }),
+            );
+        }
+
+        #[test]
+        fn borrows_single_vertex() {
+            let p = polyline();
+
+            for i in 0..p.len() {
+                let s = p.slice(i..=i);
+
+                assert_eq!(s.len(), 1);
+                assert!(s.iter().eq(p.iter().skip(i).take(1)));
+            }
+            assert_eq!(
+                p.slice(4..).bounding_box(),
+                (Point { x: 2, y: 2 }, Point { x: 2, y: 2 })
+            );
+        }
+
+        #[test]
+        #[should_panic = "vertex range (is 2..2) should be non-empty and <= len (is 5)"]
+        fn panics_on_empty_range() {
+            let _ = polyline().slice(2..2);
+        }
+
+        #[test]
+        #[should_panic = "vertex range (is 3..6) should be non-empty and <= len (is 5)"]
+        fn panics_on_out_of_bounds_range() {
+            let _ = polyline().slice(3..6);
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{Point, Polyline};
+
+impl Polyline {
+    /// Samples the uniform [Catmull–Rom spline][1] passing through the
+    /// vertices of this [`Polyline`], producing a denser one with the
+    /// `samples_per_segment` vertices per each original segment.
+    ///
+    /// All the original vertices (so both endpoints too) are preserved, while
+    /// the end segments use their endpoints as the missing control points.
+    /// Samples are rounded to the nearest integer coordinates.
+    ///
+    /// A [`Polyline`] of fewer than 4 vertices has not enough control points
+    /// for a meaningful curve, so it's returned as is, same as for the
+    /// `samples_per_segment` of `0` or `1`.
+    ///
+    /// [1]: https://en.wikipedia.org/wiki/Cubic_Hermite_spline#Catmull%E2%80%93Rom_spline
+    #[must_use]
+    pub fn to_bezier_samples(&self, samples_per_segment: usize) -> Self {
+        if self.len() < 4 || samples_per_segment < 2 {
+            return self.clone();
+        }
+
+        let vertices: Vec<_> = self.iter().copied().collect();
+        let last = vertices.len() - 1;
+        let mut sampled = Self::new(self.start);
+        for i in 0..last {
+            let controls = [
+                vertices[i.saturating_sub(1)],
+                vertices[i],
+                vertices[i + 1],
+                vertices[(i + 2).min(last)],
+            ];
+            for k in 1..samples_per_segment {
+                sampled.push(catmull_rom(controls, k as f64 / samples_per_segment as f64));
+            }
+            sampled.push(vertices[i + 1]);
+        }
+        sampled
+    }
+}
+
+/// Evaluates the uniform Catmull–Rom segment between the two middle `controls`
+/// at the `t` within `0.0..=1.0`.
+fn catmull_rom([p0, p1, p2, p3]: [Point; 4], t: f64) -> Point {
+    let (t2, t3) = (t * t, t * t * t);
+    let eval = |p0: i32, p1: i32, p2: i32, p3: i32| {
+        let [p0, p1, p2, p3] = [p0, p1, p2, p3].map(f64::from);
+        let v = 0.5
+            * (2.0 * p1
+                + (p2 - p0) * t
+                + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t2
+                + (3.0 * p1 - p0 - 3.0 * p2 + p3) * t3);
+        v.round() as i32
+    };
+    Point {
+        x: eval(p0.x, p1.x, p2.x, p3.x),
+        y: eval(p0.y, p1.y, p2.y, p3.y),
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use super::*;
+
+    mod to_bezier_samples {
+        use super::*;
+
+        fn zigzag() -> Polyline {
+            Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![
+                    Point { x: 10, y: 10 },
+                    Point { x: 20, y: 0 },
+                    Point { x: 30, y: 10 },
+                ],
+            )
+        }
+
+        #[test]
+        fn preserves_endpoints_and_vertices() {
+            let p = zigzag();
+
+            let sampled = p.to_bezier_samples(4);
+
+            assert_eq!(sampled.start(), p.start());
+            assert_eq!(sampled.iter().last(), p.iter().last());
+            for (i, v) in p.iter().enumerate() {
+                assert_eq!(sampled.iter().nth(i * 4), Some(v));
+            }
+        }
+
+        #[test]
+        fn grows_with_samples_per_segment() {
+            let p = zigzag();
+
+            assert_eq!(p.to_bezier_samples(2).len(), 7);
+            assert_eq!(p.to_bezier_samples(4).len(), 13);
+            assert_eq!(p.to_bezier_samples(10).len(), 31);
+        }
+
+        #[test]
+        fn keeps_straight_line_straight() {
+            let p: Polyline = (0..4).map(|i| Point { x: 10 * i, y: 0 }).collect();
+
+            let sampled = p.to_bezier_samples(4);
+
+            assert!(sampled.iter().all(|p| p.y == 0));
+            assert!(sampled.segments().all(|(a, b)| a.x < b.x));
+        }
+
+        #[test]
+        fn returns_short_polyline_as_is() {
+            let p = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![Point { x: 10, y: 10 }, Point { x: 20, y: 0 }],
+            );
+
+            assert_eq!(p.to_bezier_samples(8), p);
+            assert_eq!(zigzag().to_bezier_samples(1), zigzag());
+            assert_eq!(zigzag().to_bezier_samples(0), zigzag());
+        }
+    }
+}
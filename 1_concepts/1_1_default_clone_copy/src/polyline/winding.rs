@@ -0,0 +1,99 @@
+use std::{cmp::Ordering, iter};
+
+use super::intersect::{on_segment, orientation};
+use crate::{Point, Polyline};
+
+impl Polyline {
+    /// Checks whether the `query` [`Point`] lies inside this [`Polyline`]
+    /// treated as a closed polygon (its last vertex implicitly connected back
+    /// to the `start`), with the [winding number][1] algorithm.
+    ///
+    /// Points lying exactly on an edge or a vertex are considered inside.
+    /// Self-intersecting polygons contain every point with a non-zero winding
+    /// number.
+    ///
+    /// [1]: https://en.wikipedia.org/wiki/Point_in_polygon#Winding_number_algorithm
+    #[must_use]
+    pub fn contains(&self, query: Point) -> bool {
+        let last = *self.points.last().unwrap_or(&self.start);
+        let mut winding = 0_i64;
+        for (a, b) in self.segments().chain(iter::once((last, self.start))) {
+            let side = orientation(a, b, query);
+            if side == Ordering::Equal && on_segment(a, b, query) {
+                return true;
+            }
+            if a.y <= query.y {
+                if b.y > query.y && side == Ordering::Greater {
+                    winding += 1;
+                }
+            } else if b.y <= query.y && side == Ordering::Less {
+                winding -= 1;
+            }
+        }
+        winding != 0
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use super::*;
+
+    mod contains {
+        use super::*;
+
+        fn square() -> Polyline {
+            Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![
+                    Point { x: 4, y: 0 },
+                    Point { x: 4, y: 4 },
+                    Point { x: 0, y: 4 },
+                ],
+            )
+        }
+
+        #[test]
+        fn contains_point_inside() {
+            assert!(square().contains(Point { x: 2, y: 2 }));
+            assert!(square().reversed().contains(Point { x: 1, y: 3 }));
+        }
+
+        #[test]
+        fn excludes_point_outside() {
+            assert!(!square().contains(Point { x: 5, y: 2 }));
+            assert!(!square().contains(Point { x: -1, y: -1 }));
+            assert!(!square().contains(Point { x: 2, y: 8 }));
+        }
+
+        #[test]
+        fn contains_point_on_boundary() {
+            assert!(square().contains(Point { x: 4, y: 2 }));
+            assert!(square().contains(Point { x: 0, y: 2 }));
+            assert!(square().contains(Point { x: 4, y: 4 }));
+        }
+
+        #[test]
+        fn handles_concave_polygon() {
+            let p = Polyline::with_points(
+                Point { x: 0, y: 0 },
+                vec![
+                    Point { x: 6, y: 0 },
+                    Point { x: 6, y: 6 },
+                    Point { x: 3, y: 2 },
+                    Point { x: 0, y: 6 },
+                ],
+            );
+
+            assert!(p.contains(Point { x: 1, y: 1 }));
+            assert!(!p.contains(Point { x: 3, y: 4 }));
+        }
+
+        #[test]
+        fn contains_only_vertex_of_single_vertex() {
+            let p = Polyline::new(Point { x: 1, y: 1 });
+
+            assert!(p.contains(Point { x: 1, y: 1 }));
+            assert!(!p.contains(Point { x: 1, y: 2 }));
+        }
+    }
+}
@@ -1,105 +1,370 @@
 use std::{
-    fmt::Debug,
+    array,
+    borrow::Cow,
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::{self, Debug},
+    future,
     pin::{Pin, pin},
     rc::Rc,
+    sync::{Arc, Mutex},
     task::{Context, Poll},
     time::{Duration, Instant},
 };
 
-use tokio::time::sleep;
+use pin_project_lite::pin_project;
+use tokio::{
+    task::{JoinError, JoinSet},
+    time::{Timeout, sleep, timeout},
+};
 
-trait SayHi: Debug {
+trait SayHi: Debug + CustomHi {
     fn say_hi(self: Pin<&Self>) {
-        println!("Hi from {self:?}");
+        let mut out = String::new();
+        self.say_hi_to(&mut out)
+            .expect("writing into `String` never fails");
+        print!("{out}");
+    }
+
+    /// Writes the [`SayHi::say_hi`] greeting line into the provided `out`
+    /// sink instead of printing it.
+    fn say_hi_to<W: fmt::Write>(self: Pin<&Self>, out: &mut W) -> fmt::Result {
+        writeln!(out, "{}", self.greeting())
+    }
+
+    /// Greets with the provided `prefix` instead of the default `"Hi from"`.
+    fn say_hi_with(self: Pin<&Self>, prefix: &str) {
+        println!("{}", self.greeting_with(prefix));
+    }
+
+    /// Produces the message printed by [`SayHi::say_hi`], preferring the
+    /// [`CustomHi::custom_hi`] one, if any.
+    fn greeting(self: Pin<&Self>) -> String {
+        self.custom_hi()
+            .unwrap_or_else(|| self.greeting_with("Hi from"))
+    }
+
+    /// Produces the message printed by [`SayHi::say_hi_with`].
+    fn greeting_with(self: Pin<&Self>, prefix: &str) -> String {
+        format!("{prefix} {self:?}")
+    }
+
+    /// Greets like [`SayHi::say_hi`], but only once the `delay` elapses.
+    async fn say_hi_delayed(self: Pin<&Self>, delay: Duration) {
+        println!("{}", self.greeting_delayed(delay).await);
+    }
+
+    /// Produces the message printed by [`SayHi::say_hi_delayed`], holding the
+    /// pinned borrow across the `delay`.
+    async fn greeting_delayed(self: Pin<&Self>, delay: Duration) -> String {
+        sleep(delay).await;
+        self.greeting()
+    }
+}
+impl<T: Debug + CustomHi> SayHi for T {}
+
+/// Greeting of every element of a collection separately, instead of the whole
+/// one at once, like [`SayHi`] does.
+trait SayHiEach {
+    /// Greets each element on its own line.
+    fn say_hi_each(self: Pin<&Self>) {
+        let mut out = String::new();
+        self.say_hi_each_to(&mut out)
+            .expect("writing into `String` never fails");
+        print!("{out}");
+    }
+
+    /// Writes the [`SayHiEach::say_hi_each`] greeting lines into the provided
+    /// `out` sink instead of printing them.
+    fn say_hi_each_to<W: fmt::Write>(self: Pin<&Self>, out: &mut W) -> fmt::Result;
+}
+impl<T: Debug> SayHiEach for &[T] {
+    fn say_hi_each_to<W: fmt::Write>(self: Pin<&Self>, out: &mut W) -> fmt::Result {
+        self.get_ref()
+            .iter()
+            .try_for_each(|elem| writeln!(out, "Hi from {elem:?}"))
+    }
+}
+
+/// Opt-in override of the [`SayHi::say_hi`] greeting.
+///
+/// Stands in for specialization of the blanket [`SayHi`] impl, which is not
+/// stable yet: types keep the default greeting with an empty impl.
+trait CustomHi {
+    fn custom_hi(&self) -> Option<String> {
+        None
+    }
+}
+impl<T> CustomHi for Box<T> {}
+impl<T> CustomHi for Rc<T> {}
+impl<T> CustomHi for Vec<T> {}
+impl<T> CustomHi for VecDeque<T> {}
+impl<T> CustomHi for &[T] {}
+impl<T, const N: usize> CustomHi for [T; N] {}
+impl<T> CustomHi for Option<T> {}
+impl<K, V> CustomHi for HashMap<K, V> {}
+impl<T> CustomHi for HashSet<T> {}
+impl CustomHi for String {}
+impl CustomHi for Cow<'_, str> {}
+impl CustomHi for bool {}
+
+#[derive(Debug)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl CustomHi for Point {
+    fn custom_hi(&self) -> Option<String> {
+        Some(format!("Hi from point at ({},{})", self.x, self.y))
     }
 }
-impl<T: Debug> SayHi for T {}
 
 //
 trait MutMeSomehow {
     fn mut_me_somehow(self: Pin<&mut Self>);
+
+    /// Same as [`MutMeSomehow::mut_me_somehow`], but returns the mutated
+    /// value back for further chaining.
+    ///
+    /// Use [`Pin::as_ref`] on the result to call `&self` methods like
+    /// [`SayHi::say_hi`].
+    fn mut_me_chained(mut self: Pin<&mut Self>) -> Pin<&mut Self> {
+        self.as_mut().mut_me_somehow();
+        self
+    }
 }
 
-impl<T: Default> MutMeSomehow for Box<T> {
+/// Specific way of mutating a value behind the [`MutMeSomehow`] trait, which
+/// is implemented for every type having it.
+///
+/// Stands in for specialization of a blanket `impl<T: Default> MutMeSomehow`
+/// (which is not stable yet, and would conflict with the specific impls
+/// below): plain types opt into resetting to their [`Default`] value with the
+/// `impl_mut_strategy_resetting!` macro.
+trait MutStrategy {
+    fn mutate(self: Pin<&mut Self>);
+}
+impl<T: MutStrategy> MutMeSomehow for T {
     fn mut_me_somehow(self: Pin<&mut Self>) {
+        self.mutate();
+    }
+}
+
+/// Implements [`MutStrategy`] for the provided types, resetting them to their
+/// [`Default`] value.
+macro_rules! impl_mut_strategy_resetting {
+    ($($ty:ty),+ $(,)?) => {$(
+        impl MutStrategy for $ty {
+            fn mutate(mut self: Pin<&mut Self>) {
+                self.set(Default::default());
+            }
+        }
+    )+};
+}
+impl_mut_strategy_resetting!(bool, char, i32, u32, i64, u64, f64);
+
+impl<T: Default> MutStrategy for Box<T> {
+    fn mutate(self: Pin<&mut Self>) {
         **self.get_mut() = <T as Default>::default();
     }
 }
-impl<T: Default> MutMeSomehow for Rc<T> {
-    fn mut_me_somehow(self: Pin<&mut Self>) {
+impl<T: Default> MutStrategy for Rc<T> {
+    fn mutate(self: Pin<&mut Self>) {
         *self.get_mut() = Self::default();
     }
 }
-impl<T> MutMeSomehow for Vec<T> {
-    fn mut_me_somehow(mut self: Pin<&mut Self>) {
-        self.set(Self::new());
+impl<T: Unpin> MutStrategy for Vec<T> {
+    /// Clears the [`Vec`], keeping its allocated capacity for further pushes.
+    fn mutate(self: Pin<&mut Self>) {
+        self.get_mut().clear();
     }
 }
-impl MutMeSomehow for String {
-    fn mut_me_somehow(mut self: Pin<&mut Self>) {
+impl<T: Unpin> MutStrategy for VecDeque<T> {
+    /// Clears the [`VecDeque`], keeping its allocated capacity like [`Vec`]
+    /// does.
+    fn mutate(self: Pin<&mut Self>) {
+        self.get_mut().clear();
+    }
+}
+impl MutStrategy for String {
+    fn mutate(mut self: Pin<&mut Self>) {
         self.push_str(" this task is weird");
     }
 }
-impl MutMeSomehow for &[u8] {
-    fn mut_me_somehow(self: Pin<&mut Self>) {
+impl MutStrategy for Cow<'_, str> {
+    /// Appends like the [`String`] impl does, turning a [`Cow::Borrowed`]
+    /// into a [`Cow::Owned`] first.
+    fn mutate(mut self: Pin<&mut Self>) {
+        self.to_mut().push_str(" this task is weird");
+    }
+}
+impl<T> MutStrategy for Option<T> {
+    fn mutate(mut self: Pin<&mut Self>) {
+        self.set(None);
+    }
+}
+impl<K: Unpin, V: Unpin> MutStrategy for HashMap<K, V> {
+    fn mutate(self: Pin<&mut Self>) {
+        self.get_mut().clear();
+    }
+}
+impl<T: Unpin> MutStrategy for HashSet<T> {
+    fn mutate(self: Pin<&mut Self>) {
+        self.get_mut().clear();
+    }
+}
+impl<T: Default, const N: usize> MutStrategy for [T; N] {
+    /// Resets every element to its [`Default`] value.
+    fn mutate(mut self: Pin<&mut Self>) {
+        self.set(array::from_fn(|_| T::default()));
+    }
+}
+/// Implements [`MutStrategy`] for a tuple of the provided arity, resetting
+/// each of its components to the [`Default`] value.
+macro_rules! impl_mut_strategy_for_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: Default),+> MutStrategy for ($($name,)+) {
+            fn mutate(mut self: Pin<&mut Self>) {
+                self.set(Default::default());
+            }
+        }
+    };
+}
+impl_mut_strategy_for_tuple!(A);
+impl_mut_strategy_for_tuple!(A, B);
+impl_mut_strategy_for_tuple!(A, B, C);
+impl_mut_strategy_for_tuple!(A, B, C, D);
+impl MutStrategy for &[u8] {
+    fn mutate(self: Pin<&mut Self>) {
         *self.get_mut() = &[1, 2, 3];
     }
 }
 
-mod mut_me_blanket {
-    use std::pin::Pin;
-
-    pub trait MutMeSomehow {
-        fn mut_me_somehow(self: Pin<&mut Self>);
+//
+/// Source of the current [`Instant`] for measuring execution times.
+trait Clock {
+    fn now(&self) -> Instant;
+}
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> Instant {
+        (**self).now()
     }
+}
 
-    impl<T: Default> MutMeSomehow for T {
-        fn mut_me_somehow(mut self: Pin<&mut Self>) {
-            self.set(Default::default());
-        }
+/// [`Clock`] of the [`Instant::now()`] system time.
+#[derive(Clone, Copy, Debug, Default)]
+struct SystemClock;
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
     }
 }
 
-//
-struct MeasurableFuture<Fut> {
-    inner_future: Fut,
-    started_at: Option<Instant>,
+pin_project! {
+    struct MeasurableFuture<Fut, Report = fn(Duration), Clk = SystemClock> {
+        #[pin]
+        inner_future: Fut,
+        started_at: Option<Instant>,
+        report: Option<Report>,
+        clock: Clk,
+        completed: bool,
+    }
 }
 
 impl<Fut> MeasurableFuture<Fut> {
+    /// Wraps the provided [`Future`] printing its execution time once ready.
     pub const fn new(fut: Fut) -> Self {
+        Self::with_reporter(fut, print_elapsed)
+    }
+
+    /// Wraps the provided [`Future`] recording its execution time into the
+    /// provided [`Timings`] once ready, instead of printing it.
+    pub fn recording_into(
+        fut: Fut,
+        timings: &Timings,
+    ) -> MeasurableFuture<Fut, impl FnOnce(Duration) + '_> {
+        MeasurableFuture::with_reporter(fut, |elapsed| timings.record(elapsed))
+    }
+
+    /// Wraps the provided [`Future`] into a [`tokio::time::timeout()`],
+    /// resolving into an [`Elapsed`] error if it doesn't complete within the
+    /// `timeout`.
+    ///
+    /// The measured time is printed either way, once the inner [`Future`]
+    /// completes or the `timeout` fires.
+    ///
+    /// [`Elapsed`]: tokio::time::error::Elapsed
+    pub fn with_timeout(fut: Fut, timeout: Duration) -> MeasurableFuture<Timeout<Fut>>
+    where
+        Fut: Future,
+    {
+        MeasurableFuture::new(self::timeout(timeout, fut))
+    }
+
+    /// Wraps the provided [`Future`] printing its execution time once ready,
+    /// or the elapsed-so-far time if it's dropped (cancelled) before that.
+    pub const fn report_on_drop(fut: Fut) -> DropReportingFuture<Fut> {
+        DropReportingFuture::with_reporter(fut, print_elapsed_or_cancelled)
+    }
+}
+
+impl<Fut, Report: FnOnce(Duration)> MeasurableFuture<Fut, Report> {
+    /// Wraps the provided [`Future`] passing its execution time to the
+    /// `reporter` once ready, instead of printing it.
+    pub const fn with_reporter(fut: Fut, reporter: Report) -> Self {
+        Self::with_reporter_and_clock(fut, reporter, SystemClock)
+    }
+}
+
+impl<Fut, Clk: Clock> MeasurableFuture<Fut, fn(Duration), Clk> {
+    /// Wraps the provided [`Future`] printing its execution time once ready,
+    /// as measured by the provided `clock` instead of the [`SystemClock`].
+    pub const fn with_clock(fut: Fut, clock: Clk) -> Self {
+        Self::with_reporter_and_clock(fut, print_elapsed, clock)
+    }
+}
+
+impl<Fut, Report: FnOnce(Duration), Clk: Clock> MeasurableFuture<Fut, Report, Clk> {
+    /// Wraps the provided [`Future`] passing its execution time, as measured
+    /// by the provided `clock`, to the `reporter` once ready.
+    pub const fn with_reporter_and_clock(fut: Fut, reporter: Report, clock: Clk) -> Self {
         Self {
             inner_future: fut,
             started_at: None,
+            report: Some(reporter),
+            clock,
+            completed: false,
         }
     }
 }
 
-impl<Fut: Future> Future for MeasurableFuture<Fut> {
+impl<Fut, Report, Clk> Future for MeasurableFuture<Fut, Report, Clk>
+where
+    Fut: Future,
+    Report: FnOnce(Duration),
+    Clk: Clock,
+{
     type Output = Fut::Output;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        // SAFETY: Projecting the pin from Self to the inner_future field.
-        // It is safe because:
-        // * We never move out of inner_future;
-        // * If Self is pinned, inner_future must also remain pinned;
-        // * We can freely access started_at field as Option<Instant> is Unpin.
-        let (inner_pinned, started_at) = unsafe {
-            let this = self.get_unchecked_mut();
-            (
-                Pin::new_unchecked(&mut this.inner_future),
-                &mut this.started_at,
-            )
-        };
+        let this = self.project();
 
-        if started_at.is_none() {
-            *started_at = Some(Instant::now());
+        assert!(
+            !*this.completed,
+            "`MeasurableFuture` polled after completion"
+        );
+
+        if this.started_at.is_none() {
+            *this.started_at = Some(this.clock.now());
         }
 
-        match inner_pinned.poll(cx) {
+        match this.inner_future.poll(cx) {
             Poll::Ready(out) => {
-                let elapsed = started_at.unwrap().elapsed();
-                println!("Elapsed {}ns", elapsed.as_nanos());
+                let elapsed = this.clock.now() - this.started_at.unwrap();
+                *this.completed = true;
+                if let Some(report) = this.report.take() {
+                    report(elapsed);
+                }
                 Poll::Ready(out)
             }
             Poll::Pending => Poll::Pending,
@@ -107,6 +372,360 @@ impl<Fut: Future> Future for MeasurableFuture<Fut> {
     }
 }
 
+pin_project! {
+    /// [`Future`] resolving into the output of the inner one together with its
+    /// execution time.
+    struct TimedFuture<Fut> {
+        #[pin]
+        inner_future: Fut,
+        started_at: Option<Instant>,
+        completed: bool,
+    }
+}
+
+impl<Fut> TimedFuture<Fut> {
+    pub const fn new(fut: Fut) -> Self {
+        Self {
+            inner_future: fut,
+            started_at: None,
+            completed: false,
+        }
+    }
+}
+
+impl<Fut: Future> Future for TimedFuture<Fut> {
+    type Output = (Fut::Output, Duration);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+
+        assert!(!*this.completed, "`TimedFuture` polled after completion");
+
+        let started_at = *this.started_at.get_or_insert_with(Instant::now);
+
+        this.inner_future.poll(cx).map(|out| {
+            *this.completed = true;
+            (out, started_at.elapsed())
+        })
+    }
+}
+
+pin_project! {
+    /// [`Future`] resolving into the output of the inner one together with
+    /// its [`PollStats`].
+    struct InstrumentedFuture<Fut> {
+        #[pin]
+        inner_future: Fut,
+        started_at: Option<Instant>,
+        pending_since: Option<Instant>,
+        stats: PollStats,
+        completed: bool,
+    }
+}
+
+/// Statistics of polling a [`Future`], collected by an [`InstrumentedFuture`].
+#[derive(Clone, Copy, Debug)]
+struct PollStats {
+    /// Number of times the [`Future`] was polled.
+    poll_count: u32,
+
+    /// Total time the [`Future`] spent pending between its polls.
+    pending: Duration,
+
+    /// Total execution time of the [`Future`], since its first poll.
+    elapsed: Duration,
+}
+
+impl<Fut> InstrumentedFuture<Fut> {
+    pub const fn new(fut: Fut) -> Self {
+        Self {
+            inner_future: fut,
+            started_at: None,
+            pending_since: None,
+            stats: PollStats {
+                poll_count: 0,
+                pending: Duration::ZERO,
+                elapsed: Duration::ZERO,
+            },
+            completed: false,
+        }
+    }
+}
+
+impl<Fut: Future> Future for InstrumentedFuture<Fut> {
+    type Output = (Fut::Output, PollStats);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+
+        assert!(
+            !*this.completed,
+            "`InstrumentedFuture` polled after completion"
+        );
+
+        let started_at = *this.started_at.get_or_insert_with(Instant::now);
+        if let Some(since) = this.pending_since.take() {
+            this.stats.pending += since.elapsed();
+        }
+        this.stats.poll_count += 1;
+
+        match this.inner_future.poll(cx) {
+            Poll::Ready(out) => {
+                *this.completed = true;
+                this.stats.elapsed = started_at.elapsed();
+                Poll::Ready((out, *this.stats))
+            }
+            Poll::Pending => {
+                *this.pending_since = Some(Instant::now());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Stream of the outputs of multiple [`Future`]s together with their execution
+/// times, yielded in the order of completion.
+///
+/// The `futures` crate is not a dependency, so instead of implementing its
+/// `Stream` trait, this provides the same-shaped [`MeasureAll::poll_next()`]
+/// along with the [`MeasureAll::next()`] convenience.
+struct MeasureAll<Fut: Future> {
+    pending: Vec<Pin<Box<TimedFuture<Fut>>>>,
+
+    /// Outputs completed along with the yielded one, but not yielded yet.
+    ready: VecDeque<(Fut::Output, Duration)>,
+}
+
+// Neither the pending `Future`s (already boxed), nor the buffered outputs are
+// pinned structurally.
+impl<Fut: Future> Unpin for MeasureAll<Fut> {}
+
+/// Wraps each of the provided `futs` into a [`TimedFuture`], so their results
+/// are yielded as they complete, each with its own execution time.
+fn measure_all<Fut: Future>(futs: Vec<Fut>) -> MeasureAll<Fut> {
+    MeasureAll {
+        pending: futs
+            .into_iter()
+            .map(|fut| Box::pin(TimedFuture::new(fut)))
+            .collect(),
+        ready: VecDeque::new(),
+    }
+}
+
+impl<Fut: Future> MeasureAll<Fut> {
+    /// Polls all the pending [`Future`]s, yielding the output of the first
+    /// completed one, or [`None`] once all of them have been yielded.
+    ///
+    /// Every pending [`Future`] is polled on each call (buffering the outputs
+    /// completed besides the yielded one), so all of them start being timed
+    /// on the first call, regardless of which completes first.
+    pub fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<(Fut::Output, Duration)>> {
+        let Self { pending, ready } = self.get_mut();
+        pending.retain_mut(|fut| match fut.as_mut().poll(cx) {
+            Poll::Ready(out) => {
+                ready.push_back(out);
+                false
+            }
+            Poll::Pending => true,
+        });
+        match ready.pop_front() {
+            Some(out) => Poll::Ready(Some(out)),
+            None if pending.is_empty() => Poll::Ready(None),
+            None => Poll::Pending,
+        }
+    }
+
+    /// Resolves into the next completed output, if any.
+    pub async fn next(&mut self) -> Option<(Fut::Output, Duration)> {
+        future::poll_fn(|cx| Pin::new(&mut *self).poll_next(cx)).await
+    }
+}
+
+/// Joins all the tasks of the provided [`JoinSet`] in their completion order,
+/// pairing each result (including a [`JoinError`] of a panicked or aborted
+/// task) with the time elapsed since the measurement started.
+async fn measure_joinset<T: 'static>(mut set: JoinSet<T>) -> Vec<(Result<T, JoinError>, Duration)> {
+    let started_at = Instant::now();
+    let mut joined = Vec::with_capacity(set.len());
+    while let Some(res) = set.join_next().await {
+        joined.push((res, started_at.elapsed()));
+    }
+    joined
+}
+
+pin_project! {
+    /// [`MeasurableFuture`] reporting its elapsed-so-far time even when it's
+    /// dropped before completion, along with the `cancelled` flag.
+    struct DropReportingFuture<Fut, Report = fn(Duration, bool)>
+    where
+        Report: FnOnce(Duration, bool),
+    {
+        #[pin]
+        inner_future: Fut,
+        started_at: Option<Instant>,
+        report: Option<Report>,
+    }
+
+    impl<Fut, Report> PinnedDrop for DropReportingFuture<Fut, Report>
+    where
+        Report: FnOnce(Duration, bool),
+    {
+        fn drop(this: Pin<&mut Self>) {
+            let this = this.project();
+            if let Some(report) = this.report.take() {
+                report(this.started_at.map_or(Duration::ZERO, |at| at.elapsed()), true);
+            }
+        }
+    }
+}
+
+impl<Fut, Report: FnOnce(Duration, bool)> DropReportingFuture<Fut, Report> {
+    /// Wraps the provided [`Future`] passing its execution time to the
+    /// `reporter` exactly once: either on completion, or on drop if it's
+    /// still pending.
+    pub const fn with_reporter(fut: Fut, reporter: Report) -> Self {
+        Self {
+            inner_future: fut,
+            started_at: None,
+            report: Some(reporter),
+        }
+    }
+}
+
+impl<Fut: Future, Report: FnOnce(Duration, bool)> Future for DropReportingFuture<Fut, Report> {
+    type Output = Fut::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+
+        assert!(
+            this.report.is_some(),
+            "`DropReportingFuture` polled after completion"
+        );
+
+        let started_at = *this.started_at.get_or_insert_with(Instant::now);
+
+        this.inner_future.poll(cx).map(|out| {
+            if let Some(report) = this.report.take() {
+                report(started_at.elapsed(), false);
+            }
+            out
+        })
+    }
+}
+
+pin_project! {
+    /// [`MeasurableFuture`] folding its execution time into an exponentially
+    /// weighted moving average (in seconds) shared between many calls, instead
+    /// of printing it.
+    struct EwmaFuture<Fut> {
+        #[pin]
+        inner_future: TimedFuture<Fut>,
+        alpha: f64,
+        average: Arc<Mutex<f64>>,
+    }
+}
+
+impl<Fut> EwmaFuture<Fut> {
+    /// Wraps the provided [`Future`] updating the shared `average` with its
+    /// execution time once ready, as `alpha * elapsed + (1 - alpha) * average`.
+    ///
+    /// The `alpha` smoothing factor must be within `(0.0, 1.0]` range: the
+    /// closer it's to `1.0`, the faster the `average` follows recent timings
+    /// (with `1.0` keeping the last one only), while smaller values smooth out
+    /// spikes at the cost of lagging behind.
+    ///
+    /// # Panics
+    ///
+    /// If `alpha` is not within `(0.0, 1.0]` range.
+    pub fn new(fut: Fut, alpha: f64, average: Arc<Mutex<f64>>) -> Self {
+        assert!(
+            alpha > 0.0 && alpha <= 1.0,
+            "EWMA smoothing factor must be within (0, 1]"
+        );
+
+        Self {
+            inner_future: TimedFuture::new(fut),
+            alpha,
+            average,
+        }
+    }
+}
+
+impl<Fut: Future> Future for EwmaFuture<Fut> {
+    type Output = Fut::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+
+        this.inner_future.poll(cx).map(|(out, elapsed)| {
+            let mut average = this.average.lock().unwrap();
+            *average = *this.alpha * elapsed.as_secs_f64() + (1.0 - *this.alpha) * *average;
+            out
+        })
+    }
+}
+
+fn print_elapsed_or_cancelled(elapsed: Duration, cancelled: bool) {
+    println!("Elapsed {}ns (cancelled: {cancelled})", elapsed.as_nanos());
+}
+
+fn print_elapsed(elapsed: Duration) {
+    println!("Elapsed {}ns", elapsed.as_nanos());
+}
+
+/// Aggregated statistics of recorded execution times.
+#[derive(Debug, Default)]
+struct Timings {
+    /// Recorded [`Duration`]s, kept sorted.
+    sorted: Mutex<Vec<Duration>>,
+}
+
+impl Timings {
+    pub fn record(&self, elapsed: Duration) {
+        let mut sorted = self.sorted.lock().unwrap();
+        let at = sorted.partition_point(|d| *d <= elapsed);
+        sorted.insert(at, elapsed);
+    }
+
+    pub fn count(&self) -> usize {
+        self.sorted.lock().unwrap().len()
+    }
+
+    pub fn mean(&self) -> Option<Duration> {
+        let sorted = self.sorted.lock().unwrap();
+        let count = u32::try_from(sorted.len()).ok().filter(|n| *n > 0)?;
+        Some(sorted.iter().sum::<Duration>() / count)
+    }
+
+    pub fn min(&self) -> Option<Duration> {
+        self.sorted.lock().unwrap().first().copied()
+    }
+
+    pub fn max(&self) -> Option<Duration> {
+        self.sorted.lock().unwrap().last().copied()
+    }
+
+    /// Nearest-rank `p`-th percentile of the recorded [`Duration`]s.
+    ///
+    /// # Panics
+    ///
+    /// If `p` is not within `0.0..=100.0` range.
+    pub fn percentile(&self, p: f64) -> Option<Duration> {
+        assert!(
+            (0.0..=100.0).contains(&p),
+            "percentile must be within 0..=100"
+        );
+
+        let sorted = self.sorted.lock().unwrap();
+        let rank = (p / 100.0 * sorted.len() as f64).ceil() as usize;
+        sorted.get(rank.saturating_sub(1)).copied()
+    }
+}
+
 #[tokio::main]
 async fn main() {
     let mut p = pin!(Box::new("box"));
@@ -128,24 +747,810 @@ async fn main() {
     println!();
 
     let mut p = pin!(String::from("For sure"));
+    p.as_ref().say_hi_with("Hello from");
+    p.as_mut().mut_me_chained().as_ref().say_hi();
+    println!();
+
+    let mut p = pin!(Cow::Borrowed("Cow"));
     p.as_ref().say_hi();
     p.as_mut().mut_me_somehow();
     p.as_ref().say_hi();
     println!();
 
+    let mut p = pin!([4, 2]);
+    p.as_ref().say_hi();
+    p.as_mut().mut_me_somehow();
+    p.as_ref().say_hi();
+    println!();
+
+    pin!(["each", "on", "own line"].as_slice())
+        .as_ref()
+        .say_hi_each();
+    println!();
+
     let mut p = pin!([42].as_slice());
     p.as_ref().say_hi();
     p.as_mut().mut_me_somehow();
     p.as_ref().say_hi();
     println!();
 
+    let mut p = pin!(Some("option"));
+    p.as_ref().say_hi();
+    p.as_mut().mut_me_somehow();
+    p.as_ref().say_hi();
+    println!();
+
+    let mut p = pin!(VecDeque::from(["vec", "deque"]));
+    p.as_ref().say_hi();
+    p.as_mut().mut_me_somehow();
+    p.as_ref().say_hi();
+    println!();
+
+    let mut p = pin!(HashMap::from([("hash", "map")]));
+    p.as_ref().say_hi();
+    p.as_mut().mut_me_somehow();
+    p.as_ref().say_hi();
+    println!();
+
+    let mut p = pin!(HashSet::from(["hash set"]));
+    p.as_ref().say_hi();
+    p.as_mut().mut_me_somehow();
+    p.as_ref().say_hi();
+    println!();
+
+    let p = pin!(Point { x: 1, y: 2 });
+    p.as_ref().say_hi();
+    p.as_ref().say_hi_delayed(Duration::from_millis(1)).await;
+    println!();
+
     let mut p = pin!(true);
     p.as_ref().say_hi();
-    mut_me_blanket::MutMeSomehow::mut_me_somehow(p.as_mut());
+    p.as_mut().mut_me_somehow();
     p.as_ref().say_hi();
     println!();
 
     MeasurableFuture::new(sleep(Duration::from_micros(1))).await;
     MeasurableFuture::new(sleep(Duration::from_millis(1))).await;
     MeasurableFuture::new(sleep(Duration::from_secs(1))).await;
+    MeasurableFuture::with_reporter(sleep(Duration::from_millis(1)), |elapsed| {
+        println!("Reported {}ms", elapsed.as_millis());
+    })
+    .await;
+    MeasurableFuture::with_clock(sleep(Duration::from_millis(1)), SystemClock).await;
+    MeasurableFuture::report_on_drop(sleep(Duration::from_millis(1))).await;
+    drop(MeasurableFuture::report_on_drop(sleep(
+        Duration::from_millis(1),
+    )));
+    let res =
+        MeasurableFuture::with_timeout(sleep(Duration::from_secs(1)), Duration::from_millis(1))
+            .await;
+    println!("Timed out: {}", res.is_err());
+
+    let (out, elapsed) = TimedFuture::new(async { 42 }).await;
+    println!("Got {out} in {}ns", elapsed.as_nanos());
+
+    let (out, stats) = InstrumentedFuture::new(sleep(Duration::from_millis(1))).await;
+    println!("Got {out:?} with {stats:?}");
+
+    let mut all = measure_all(
+        vec![3, 1, 2]
+            .into_iter()
+            .map(|ms| async move {
+                sleep(Duration::from_millis(ms)).await;
+                ms
+            })
+            .collect(),
+    );
+    while let Some((ms, elapsed)) = all.next().await {
+        println!("Slept {ms}ms in {}us", elapsed.as_micros());
+    }
+
+    let mut set = JoinSet::new();
+    for ms in [3, 1, 2] {
+        set.spawn(async move {
+            sleep(Duration::from_millis(ms)).await;
+            ms
+        });
+    }
+    for (res, elapsed) in measure_joinset(set).await {
+        println!("Joined {res:?} in {}us", elapsed.as_micros());
+    }
+
+    let timings = Timings::default();
+    for ms in [1, 2, 3] {
+        MeasurableFuture::recording_into(sleep(Duration::from_millis(ms)), &timings).await;
+    }
+    println!(
+        "Recorded {} timings: min {:?}, mean {:?}, p50 {:?}, max {:?}",
+        timings.count(),
+        timings.min().unwrap(),
+        timings.mean().unwrap(),
+        timings.percentile(50.0).unwrap(),
+        timings.max().unwrap(),
+    );
+
+    let average = Arc::new(Mutex::new(0.0));
+    for ms in [1, 2, 3] {
+        EwmaFuture::new(sleep(Duration::from_millis(ms)), 0.5, Arc::clone(&average)).await;
+    }
+    println!("Moving average {:.3}s", *average.lock().unwrap());
+}
+
+#[cfg(test)]
+mod spec {
+    use super::*;
+
+    mod say_hi {
+        use super::*;
+
+        #[test]
+        fn writes_greetings_into_sink() {
+            let mut out = String::new();
+
+            pin!(Box::new("box")).as_ref().say_hi_to(&mut out).unwrap();
+            pin!(Point { x: 1, y: 2 })
+                .as_ref()
+                .say_hi_to(&mut out)
+                .unwrap();
+            pin!(true).as_ref().say_hi_to(&mut out).unwrap();
+
+            assert_eq!(
+                out,
+                "Hi from \"box\"\nHi from point at (1,2)\nHi from true\n",
+            );
+        }
+
+        #[tokio::test]
+        async fn greets_after_delay() {
+            let p = pin!(Point { x: 3, y: 4 });
+            let delay = Duration::from_millis(20);
+
+            let started_at = Instant::now();
+            let greeting = p.as_ref().greeting_delayed(delay).await;
+
+            assert!(started_at.elapsed() >= delay);
+            assert_eq!(greeting, "Hi from point at (3,4)");
+        }
+
+        #[tokio::test]
+        async fn keeps_pinned_borrow_across_await() {
+            let p = pin!(vec![1, 2]);
+            let pinned = p.as_ref();
+
+            let greeting = pinned.greeting_delayed(Duration::from_millis(1)).await;
+
+            assert_eq!(greeting, pinned.greeting());
+            assert_eq!(greeting, "Hi from [1, 2]");
+        }
+
+        #[test]
+        fn greets_box() {
+            let p = pin!(Box::new("box"));
+
+            assert_eq!(p.as_ref().greeting(), r#"Hi from "box""#);
+        }
+
+        #[test]
+        fn greets_vec() {
+            let p = pin!(vec![1, 2]);
+
+            assert_eq!(p.as_ref().greeting(), "Hi from [1, 2]");
+        }
+
+        #[test]
+        fn greets_string() {
+            let p = pin!(String::from("string"));
+
+            assert_eq!(p.as_ref().greeting(), r#"Hi from "string""#);
+        }
+
+        #[test]
+        fn prefers_custom_greeting() {
+            let p = pin!(Point { x: 3, y: -4 });
+
+            assert_eq!(p.as_ref().greeting(), "Hi from point at (3,-4)");
+        }
+
+        #[test]
+        fn defaults_without_custom_greeting() {
+            let p = pin!(Some(1));
+
+            assert_eq!(p.as_ref().custom_hi(), None);
+            assert_eq!(p.as_ref().greeting(), "Hi from Some(1)");
+        }
+
+        #[test]
+        fn honors_custom_prefix() {
+            let p = pin!(vec![1, 2]);
+
+            assert_eq!(p.as_ref().greeting_with("Hello from"), "Hello from [1, 2]");
+        }
+    }
+
+    mod say_hi_each {
+        use super::*;
+
+        #[test]
+        fn greets_every_element_on_own_line() {
+            let mut out = String::new();
+
+            pin!([1, 2, 3].as_slice())
+                .as_ref()
+                .say_hi_each_to(&mut out)
+                .unwrap();
+
+            assert_eq!(out, "Hi from 1\nHi from 2\nHi from 3\n");
+        }
+
+        #[test]
+        fn greets_nothing_for_empty_slice() {
+            let mut out = String::new();
+
+            pin!(<&[bool]>::default())
+                .as_ref()
+                .say_hi_each_to(&mut out)
+                .unwrap();
+
+            assert!(out.is_empty());
+        }
+    }
+
+    mod mut_me_somehow {
+        use super::*;
+
+        fn mut_me<T: MutMeSomehow>(p: Pin<&mut T>) {
+            p.mut_me_somehow();
+        }
+
+        #[test]
+        fn applies_container_and_default_strategies_via_same_trait() {
+            let mut boxed = pin!(Box::new(42));
+            let mut flag = pin!(true);
+
+            mut_me(boxed.as_mut());
+            mut_me(flag.as_mut());
+
+            assert_eq!(**boxed, 0);
+            assert!(!*flag);
+        }
+
+        #[test]
+        fn resets_option_to_none() {
+            let mut p = pin!(Some(String::from("some")));
+
+            p.as_mut().mut_me_somehow();
+
+            assert_eq!(*p, None);
+        }
+
+        #[test]
+        fn clears_vec_keeping_capacity() {
+            let mut p = pin!(Vec::new());
+            for i in 0..100 {
+                p.push(i);
+            }
+            let capacity = p.capacity();
+
+            p.as_mut().mut_me_somehow();
+
+            assert!(p.is_empty());
+            assert_ne!(p.capacity(), 0);
+            assert_eq!(p.capacity(), capacity);
+        }
+
+        #[test]
+        fn resets_array_elements() {
+            let mut p = pin!([1, -2, 3, i32::MAX]);
+
+            p.as_mut().mut_me_somehow();
+
+            assert_eq!(*p, [0; 4]);
+        }
+
+        #[test]
+        fn resets_tuple_components() {
+            let mut p = pin!((String::from("tuple"), 42, true));
+
+            p.as_mut().mut_me_somehow();
+
+            assert_eq!(*p, (String::new(), 0, false));
+        }
+
+        #[test]
+        fn resets_tuples_of_any_supported_arity() {
+            let mut one = pin!((1,));
+            let mut four = pin!((1, 'a', Some(()), vec![1]));
+
+            one.as_mut().mut_me_somehow();
+            four.as_mut().mut_me_somehow();
+
+            assert_eq!(*one, (0,));
+            assert_eq!(*four, (0, '\0', None, vec![]));
+        }
+
+        #[test]
+        fn promotes_borrowed_cow_to_owned() {
+            let mut p = pin!(Cow::Borrowed("cow"));
+
+            p.as_mut().mut_me_somehow();
+
+            assert!(matches!(*p, Cow::Owned(_)));
+            assert_eq!(*p, "cow this task is weird");
+        }
+
+        #[test]
+        fn appends_to_owned_cow() {
+            let mut p: Pin<&mut Cow<'_, str>> = pin!(Cow::Owned(String::from("cow")));
+
+            p.as_mut().mut_me_somehow();
+            p.as_mut().mut_me_somehow();
+
+            assert!(matches!(*p, Cow::Owned(_)));
+            assert_eq!(*p, "cow this task is weird this task is weird");
+        }
+
+        #[test]
+        fn chains_string_mutations() {
+            let mut p = pin!(String::from("So"));
+
+            let greeting = p
+                .as_mut()
+                .mut_me_chained()
+                .mut_me_chained()
+                .as_ref()
+                .greeting();
+
+            assert_eq!(
+                greeting,
+                r#"Hi from "So this task is weird this task is weird""#,
+            );
+        }
+
+        #[test]
+        fn clears_vec_deque_keeping_capacity() {
+            let mut p = pin!(VecDeque::from([1, 2, 3]));
+            p.push_front(0);
+            let capacity = p.capacity();
+
+            p.as_mut().mut_me_somehow();
+
+            assert!(p.is_empty());
+            assert_eq!(p.front(), None);
+            assert_eq!(p.back(), None);
+            assert_eq!(p.capacity(), capacity);
+        }
+
+        #[test]
+        fn clears_hash_map_keeping_capacity() {
+            let mut p = pin!(HashMap::from([(1, "one"), (2, "two")]));
+            let capacity = p.capacity();
+
+            p.as_mut().mut_me_somehow();
+
+            assert!(p.is_empty());
+            assert_eq!(p.capacity(), capacity);
+        }
+
+        #[test]
+        fn clears_hash_set_keeping_capacity() {
+            let mut p = pin!(HashSet::from([1, 2, 3]));
+            let capacity = p.capacity();
+
+            p.as_mut().mut_me_somehow();
+
+            assert!(p.is_empty());
+            assert_eq!(p.capacity(), capacity);
+        }
+    }
+
+    mod measurable_future {
+        use std::{
+            cell::Cell,
+            future,
+            marker::{PhantomData, PhantomPinned},
+            mem,
+            sync::{Arc, Mutex},
+            task::Waker,
+        };
+
+        use super::*;
+
+        /// [`Clock`] standing still until explicitly advanced.
+        struct MockClock(Cell<Instant>);
+
+        impl MockClock {
+            fn new() -> Self {
+                Self(Cell::new(Instant::now()))
+            }
+
+            fn advance(&self, by: Duration) {
+                self.0.set(self.0.get() + by);
+            }
+        }
+
+        impl Clock for MockClock {
+            fn now(&self) -> Instant {
+                self.0.get()
+            }
+        }
+
+        #[test]
+        fn reports_elapsed_by_clock_exactly() {
+            let clock = MockClock::new();
+            let reported = Cell::new(None);
+            let mut pending = true;
+            let mut fut = pin!(MeasurableFuture::with_reporter_and_clock(
+                future::poll_fn(|_| if mem::take(&mut pending) {
+                    Poll::Pending
+                } else {
+                    Poll::Ready(())
+                }),
+                |elapsed| reported.set(Some(elapsed)),
+                &clock,
+            ));
+            let mut cx = Context::from_waker(Waker::noop());
+
+            clock.advance(Duration::from_secs(100));
+            assert!(fut.as_mut().poll(&mut cx).is_pending());
+            clock.advance(Duration::from_millis(42));
+            assert!(fut.as_mut().poll(&mut cx).is_ready());
+
+            assert_eq!(reported.get(), Some(Duration::from_millis(42)));
+        }
+
+        #[test]
+        fn prints_elapsed_by_clock() {
+            let clock = MockClock::new();
+            let mut fut = pin!(MeasurableFuture::with_clock(future::ready(7), &clock));
+
+            let out = fut.as_mut().poll(&mut Context::from_waker(Waker::noop()));
+
+            assert_eq!(out, Poll::Ready(7));
+            assert!(fut.report.is_none(), "elapsed is reported");
+        }
+
+        #[tokio::test]
+        async fn reports_elapsed_once() {
+            let reported = Arc::new(Mutex::new(None));
+            let slot = Arc::clone(&reported);
+
+            MeasurableFuture::with_reporter(sleep(Duration::from_millis(1)), move |elapsed| {
+                assert!(slot.lock().unwrap().replace(elapsed).is_none());
+            })
+            .await;
+
+            let elapsed = reported.lock().unwrap().expect("elapsed is reported");
+            assert!(elapsed >= Duration::from_millis(1));
+        }
+
+        #[tokio::test]
+        async fn completes_within_timeout() {
+            let mut fut = pin!(MeasurableFuture::with_timeout(
+                async { 42 },
+                Duration::from_secs(1),
+            ));
+
+            assert_eq!(fut.as_mut().await, Ok(42));
+            assert!(fut.report.is_none(), "elapsed is reported");
+        }
+
+        #[tokio::test]
+        async fn errors_on_timeout() {
+            let mut fut = pin!(MeasurableFuture::with_timeout(
+                sleep(Duration::from_secs(10)),
+                Duration::from_millis(1),
+            ));
+
+            assert!(fut.as_mut().await.is_err());
+            assert!(fut.report.is_none(), "elapsed is reported");
+        }
+
+        #[test]
+        #[should_panic = "`MeasurableFuture` polled after completion"]
+        fn panics_when_polled_after_completion() {
+            let mut fut = pin!(MeasurableFuture::with_reporter(future::ready(()), drop));
+            let mut cx = Context::from_waker(Waker::noop());
+
+            assert!(fut.as_mut().poll(&mut cx).is_ready());
+            let _ = fut.as_mut().poll(&mut cx);
+        }
+
+        /// Resolves [`Unpin`]-ness of a concrete type at compile time, as the
+        /// inherent `UNPIN` shadows the trait one whenever `T: Unpin`.
+        struct Check<T>(PhantomData<T>);
+        trait NotUnpin {
+            const UNPIN: bool = false;
+        }
+        impl<T> NotUnpin for Check<T> {}
+        impl<T: Unpin> Check<T> {
+            const UNPIN: bool = true;
+        }
+
+        #[test]
+        fn is_unpin_only_when_inner_future_is() {
+            const { assert!(Check::<MeasurableFuture<future::Ready<()>>>::UNPIN) };
+            const { assert!(!Check::<MeasurableFuture<PhantomPinned>>::UNPIN) };
+            const { assert!(Check::<TimedFuture<future::Ready<()>>>::UNPIN) };
+            const { assert!(!Check::<TimedFuture<PhantomPinned>>::UNPIN) };
+        }
+    }
+
+    mod timed_future {
+        use super::*;
+
+        #[tokio::test]
+        async fn returns_output_with_elapsed() {
+            let interval = Duration::from_millis(5);
+
+            let (out, elapsed) = TimedFuture::new(async {
+                sleep(interval).await;
+                "done"
+            })
+            .await;
+
+            assert_eq!(out, "done");
+            assert!(elapsed >= interval);
+        }
+    }
+
+    mod drop_reporting_future {
+        use std::{cell::Cell, task::Waker};
+
+        use super::*;
+
+        #[tokio::test]
+        async fn reports_completion_once() {
+            let reported = Cell::new(None);
+
+            DropReportingFuture::with_reporter(
+                sleep(Duration::from_millis(1)),
+                |elapsed, cancelled| {
+                    assert!(reported.replace(Some((elapsed, cancelled))).is_none());
+                },
+            )
+            .await;
+
+            let (elapsed, cancelled) = reported.get().expect("elapsed is reported");
+            assert!(elapsed >= Duration::from_millis(1));
+            assert!(!cancelled);
+        }
+
+        #[tokio::test]
+        async fn reports_cancellation_on_drop() {
+            let reported = Cell::new(None);
+            let mut cx = Context::from_waker(Waker::noop());
+
+            {
+                let mut fut = pin!(DropReportingFuture::with_reporter(
+                    sleep(Duration::from_secs(10)),
+                    |elapsed, cancelled| {
+                        assert!(reported.replace(Some((elapsed, cancelled))).is_none());
+                    },
+                ));
+                assert!(fut.as_mut().poll(&mut cx).is_pending());
+                assert!(reported.get().is_none());
+            }
+
+            let (_, cancelled) = reported.get().expect("cancellation is reported");
+            assert!(cancelled);
+        }
+    }
+
+    mod instrumented_future {
+        use super::*;
+
+        #[tokio::test]
+        async fn counts_polls_of_yielding_future() {
+            let (out, stats) = InstrumentedFuture::new(async {
+                tokio::task::yield_now().await;
+                42
+            })
+            .await;
+
+            assert_eq!(out, 42);
+            assert!(stats.poll_count >= 2);
+            assert!(stats.pending <= stats.elapsed);
+        }
+
+        #[tokio::test]
+        async fn polls_ready_future_once() {
+            let (_, stats) = InstrumentedFuture::new(async {}).await;
+
+            assert_eq!(stats.poll_count, 1);
+            assert_eq!(stats.pending, Duration::ZERO);
+        }
+
+        #[tokio::test]
+        async fn measures_pending_time() {
+            let interval = Duration::from_millis(5);
+
+            let (_, stats) = InstrumentedFuture::new(sleep(interval)).await;
+
+            assert!(stats.pending >= interval / 2);
+            assert!(stats.elapsed >= interval);
+        }
+    }
+
+    mod measure_all {
+        use super::*;
+
+        #[tokio::test]
+        async fn yields_every_output_with_own_elapsed() {
+            let mut all = measure_all(
+                [30, 10, 20]
+                    .map(|ms| async move {
+                        sleep(Duration::from_millis(ms)).await;
+                        ms
+                    })
+                    .into(),
+            );
+
+            let mut reported = vec![];
+            while let Some((ms, elapsed)) = all.next().await {
+                assert!(elapsed >= Duration::from_millis(ms));
+                reported.push(ms);
+            }
+
+            assert_eq!(reported, [10, 20, 30]);
+            assert_eq!(all.next().await, None);
+        }
+
+        #[tokio::test]
+        async fn starts_timing_all_futures_on_first_poll() {
+            let mut all = measure_all(
+                [0, 30, 0]
+                    .map(|ms| async move {
+                        if ms > 0 {
+                            sleep(Duration::from_millis(ms)).await;
+                        }
+                        ms
+                    })
+                    .into(),
+            );
+
+            assert_eq!(all.next().await.map(|(ms, _)| ms), Some(0));
+            sleep(Duration::from_millis(50)).await;
+
+            // Buffered from the first poll already.
+            assert_eq!(all.next().await.map(|(ms, _)| ms), Some(0));
+            let (ms, elapsed) = all.next().await.unwrap();
+            assert_eq!(ms, 30);
+            assert!(elapsed >= Duration::from_millis(50), "{elapsed:?}");
+            assert_eq!(all.next().await, None);
+        }
+
+        #[tokio::test]
+        async fn yields_nothing_for_no_futures() {
+            let mut all = measure_all(Vec::<future::Ready<()>>::new());
+
+            assert_eq!(all.next().await, None);
+        }
+    }
+
+    mod measure_joinset {
+        use super::*;
+
+        #[tokio::test]
+        async fn captures_every_task_with_elapsed() {
+            let mut set = JoinSet::new();
+            for ms in [30, 10, 20] {
+                set.spawn(async move {
+                    sleep(Duration::from_millis(ms)).await;
+                    ms
+                });
+            }
+
+            let joined = measure_joinset(set).await;
+
+            assert_eq!(joined.len(), 3);
+            let mut reported = vec![];
+            for (res, elapsed) in joined {
+                let ms = res.unwrap();
+                assert!(elapsed >= Duration::from_millis(ms));
+                reported.push(ms);
+            }
+            assert_eq!(reported, [10, 20, 30]);
+        }
+
+        #[tokio::test]
+        async fn preserves_join_errors() {
+            let mut set = JoinSet::new();
+            set.spawn(async { 1 });
+            set.spawn(future::pending()).abort();
+
+            let mut joined = measure_joinset(set).await;
+            joined.sort_by_key(|(res, _)| res.is_err());
+
+            assert!(matches!(joined[0].0, Ok(1)));
+            assert!(joined[1].0.as_ref().unwrap_err().is_cancelled());
+        }
+
+        #[tokio::test]
+        async fn returns_nothing_for_empty_set() {
+            assert!(measure_joinset(JoinSet::<()>::new()).await.is_empty());
+        }
+    }
+
+    mod timings {
+        use super::*;
+
+        #[tokio::test]
+        async fn records_every_completion() {
+            let timings = Timings::default();
+
+            for ms in [3, 1, 2] {
+                MeasurableFuture::recording_into(sleep(Duration::from_millis(ms)), &timings).await;
+            }
+
+            assert_eq!(timings.count(), 3);
+            assert!(timings.max() >= timings.min());
+            assert!(timings.min().unwrap() >= Duration::from_millis(1));
+        }
+
+        #[test]
+        fn aggregates_recorded_durations() {
+            let timings = Timings::default();
+            for ms in [40, 10, 30, 20] {
+                timings.record(Duration::from_millis(ms));
+            }
+
+            assert_eq!(timings.min(), Some(Duration::from_millis(10)));
+            assert_eq!(timings.max(), Some(Duration::from_millis(40)));
+            assert_eq!(timings.mean(), Some(Duration::from_millis(25)));
+            assert_eq!(timings.percentile(0.0), Some(Duration::from_millis(10)));
+            assert_eq!(timings.percentile(50.0), Some(Duration::from_millis(20)));
+            assert_eq!(timings.percentile(75.0), Some(Duration::from_millis(30)));
+            assert_eq!(timings.percentile(100.0), Some(Duration::from_millis(40)));
+        }
+
+        #[test]
+        fn is_empty_by_default() {
+            let timings = Timings::default();
+
+            assert_eq!(timings.count(), 0);
+            assert_eq!(timings.mean(), None);
+            assert_eq!(timings.min(), None);
+            assert_eq!(timings.percentile(50.0), None);
+        }
+    }
+
+    mod ewma_future {
+        use std::task::Waker;
+
+        use super::*;
+
+        #[tokio::test]
+        async fn moves_toward_steady_state() {
+            let interval = Duration::from_millis(10);
+            let average = Arc::new(Mutex::new(0.0));
+
+            let mut lower_bound = 0.0;
+            for _ in 0..5 {
+                EwmaFuture::new(sleep(interval), 0.5, Arc::clone(&average)).await;
+
+                // Every timing is at least the `interval` long, so the
+                // average closes at least half of its gap each time.
+                lower_bound = 0.5 * interval.as_secs_f64() + 0.5 * lower_bound;
+                assert!(*average.lock().unwrap() >= lower_bound);
+            }
+            assert!(*average.lock().unwrap() >= 0.96 * interval.as_secs_f64());
+        }
+
+        #[test]
+        fn keeps_last_timing_only_with_unit_alpha() {
+            let average = Arc::new(Mutex::new(100.0));
+
+            let mut fut = pin!(EwmaFuture::new(future::ready(7), 1.0, Arc::clone(&average)));
+
+            let out = fut.as_mut().poll(&mut Context::from_waker(Waker::noop()));
+            assert_eq!(out, Poll::Ready(7));
+            assert!(*average.lock().unwrap() < 1.0);
+        }
+
+        #[test]
+        #[should_panic = "EWMA smoothing factor must be within (0, 1]"]
+        fn panics_on_zero_alpha() {
+            drop(EwmaFuture::new(future::ready(()), 0.0, Arc::default()));
+        }
+    }
 }